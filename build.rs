@@ -0,0 +1,145 @@
+//! Generates the codec metadata table used by `codec_info` from the vendored multicodec table
+//! (see codec-table/README.md), applying this crate's opinions on top of it, such as the codec
+//! categories and expected key lengths.
+
+use std::{collections::BTreeMap, fmt::Write as _};
+
+const TABLE_CSV_PATH: &str = "codec-table/table.csv";
+
+// Defines EXTRA_ROW_V and parse_table_csv, which are shared with the tests in src/codec_info.rs.
+include!("codec-table/table_csv.rs");
+
+/// Names of constants in the ssi_multicodec crate that don't follow the usual convention of
+/// uppercasing the name and replacing `-` with `_`.
+const CONST_NAME_OVERRIDE_V: &[(&str, &str)] = &[
+    ("bls12_381-g1-sig", "BLS_12381_G1_SIG"),
+    ("bls12_381-g2-sig", "BLS_12381_G2_SIG"),
+];
+
+/// The expected byte lengths of key material, as (name, byte length).
+/// References:
+/// - <https://w3c-ccg.github.io/did-key-spec/#signature-method-creation-algorithm>
+const EXPECTED_LEN_V: &[(&str, usize)] = &[
+    // Private key types
+    ("ed25519-priv", 32),
+    ("ed448-priv", 57),
+    ("p256-priv", 32),
+    ("p384-priv", 48),
+    // Reference: https://software-dl.ti.com/simplelink/esd/simplelink_lowpower_f3_sdk/8.10.00.55/exports/docs/drivers/doxygen/html/group__nistp521__params.html#ga44f08f1390d90127f0decb3c08c82664
+    ("p521-priv", 66),
+    ("secp256k1-priv", 32),
+    // Public key types
+    ("ed25519-pub", 32),
+    ("ed448-pub", 57),
+    // Compressed format.
+    ("p256-pub", 33),
+    // Compressed format.
+    ("p384-pub", 49),
+    // Compressed format.
+    ("p521-pub", 67),
+    // Compressed format.
+    ("secp256k1-pub", 33),
+    ("x25519-pub", 32),
+];
+
+/// Returns the name of the `CodecCategory` variant for the given codec.  This is based on the tag,
+/// and for the `key` tag, also on the name.
+fn codec_category(name: &str, tag: &str) -> &'static str {
+    match tag {
+        "multihash" => "Multihash",
+        "key" if name.ends_with("-pub") => "PubKey",
+        "key" if name.ends_with("-priv") => "PrivKey",
+        "key" if name.starts_with("aes-") || name.starts_with("chacha-") => "SymmetricKey",
+        "varsig" => "Signature",
+        _ => "Unspecified",
+    }
+}
+
+/// Returns the name of the codec that is the public key for the given private key codec, or vice versa.
+fn paired_name(name: &str) -> Option<String> {
+    if let Some(stem) = name.strip_suffix("-pub") {
+        Some(format!("{}-priv", stem))
+    } else {
+        name.strip_suffix("-priv")
+            .map(|stem| format!("{}-pub", stem))
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", TABLE_CSV_PATH);
+    println!("cargo:rerun-if-changed=codec-table/table_csv.rs");
+
+    let table_csv = std::fs::read_to_string(TABLE_CSV_PATH)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", TABLE_CSV_PATH, e));
+
+    // Maps code to (name, tag).  A BTreeMap keeps the output sorted by code, as `codec_info` requires.
+    let mut row_m: BTreeMap<u64, (String, String)> = parse_table_csv(&table_csv)
+        .into_iter()
+        .map(|(name, tag, code)| (code, (name, tag)))
+        .collect();
+    for &(name, tag, code) in EXTRA_ROW_V {
+        if row_m.values().any(|(row_name, _)| row_name == name) {
+            continue;
+        }
+        let previous_o = row_m.insert(code, (name.to_string(), tag.to_string()));
+        assert!(
+            previous_o.is_none(),
+            "extra row {:?} collides with code 0x{:02x} in {}",
+            name,
+            code,
+            TABLE_CSV_PATH
+        );
+    }
+    let code_by_name_m: BTreeMap<&str, u64> = row_m
+        .iter()
+        .map(|(code, (name, _tag))| (name.as_str(), *code))
+        .collect();
+
+    let mut output = String::new();
+    writeln!(
+        output,
+        "// This file was generated by build.rs from {}.  Do not edit it by hand.",
+        TABLE_CSV_PATH
+    )
+    .unwrap();
+    writeln!(output, "static CODEC_INFO_V: &[CodecInfo] = &[").unwrap();
+    for (code, (name, tag)) in &row_m {
+        let const_name = CONST_NAME_OVERRIDE_V
+            .iter()
+            .find(|(override_name, _)| override_name == name)
+            .map(|(_, const_name)| const_name.to_string())
+            .unwrap_or_else(|| name.to_uppercase().replace('-', "_"));
+        let expected_len_o = EXPECTED_LEN_V
+            .iter()
+            .find(|(expected_len_name, _)| expected_len_name == name)
+            .map(|(_, expected_len)| *expected_len);
+        let paired_codec_o = if tag == "key" {
+            paired_name(name).and_then(|paired_name| code_by_name_m.get(paired_name.as_str()))
+        } else {
+            None
+        };
+        let paired_codec_str = match paired_codec_o {
+            Some(paired_codec) => format!("Some(0x{:02x})", paired_codec),
+            None => "None".to_string(),
+        };
+        writeln!(
+            output,
+            "    CodecInfo {{ code: 0x{:02x}, name: {:?}, const_name: {:?}, tag: {:?}, category: CodecCategory::{}, expected_len_o: {:?}, paired_codec_o: {} }},",
+            code,
+            name,
+            const_name,
+            tag,
+            codec_category(name, tag),
+            expected_len_o,
+            paired_codec_str
+        )
+        .unwrap();
+    }
+    writeln!(output, "];").unwrap();
+
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let out_path = std::path::Path::new(&out_dir).join("codec_info_table.rs");
+    std::fs::write(&out_path, output)
+        .unwrap_or_else(|e| panic!("failed to write {}: {}", out_path.display(), e));
+}
@@ -0,0 +1,90 @@
+// Parsing of the vendored multicodec table.  This file is `include!`d by both build.rs (to generate
+// the table behind `codec_info`) and the tests in src/codec_info.rs (to check the generated table
+// against the rows of table.csv), so that the tests exercise the same parser that generates the table.
+
+/// Codecs that this crate supports but which are not (yet) in the multicodec table, as (name, tag, code).
+/// If the multicodec table gains a row with one of these names, that row is used instead.
+const EXTRA_ROW_V: &[(&str, &str, u64)] = &[
+    // NOTE: The codec ED448_PRIV is not yet in the multicodec table (or the ssi_multicodec crate),
+    // hence the hardcoded value.  See https://github.com/multiformats/multicodec/pull/390
+    ("ed448-priv", "key", 0x1311),
+];
+
+/// Parses the multicodec table into (name, tag, code) rows, in the order they appear.  The `name`,
+/// `tag`, and `code` columns are found by their names in the header line and the other columns
+/// (e.g. status and description) are ignored, so the official table can be used unmodified.  Panics
+/// with the offending line number if the table is malformed or has duplicate codes.
+fn parse_table_csv(table_csv: &str) -> Vec<(String, String, u64)> {
+    let mut line_iter = table_csv.lines().enumerate();
+    let (_, header_line) = line_iter
+        .next()
+        .unwrap_or_else(|| panic!("table.csv: expected a header line"));
+    let header_field_v = split_csv_line(header_line);
+    let column_index = |column_name: &str| {
+        header_field_v
+            .iter()
+            .position(|header_field| header_field == column_name)
+            .unwrap_or_else(|| {
+                panic!(
+                    "table.csv: expected a {:?} column in the header",
+                    column_name
+                )
+            })
+    };
+    let (name_index, tag_index, code_index) = (
+        column_index("name"),
+        column_index("tag"),
+        column_index("code"),
+    );
+
+    let mut row_v: Vec<(String, String, u64)> = Vec::new();
+    for (line_index, line) in line_iter {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut field_v = split_csv_line(line);
+        assert!(
+            field_v.len() > name_index.max(tag_index).max(code_index),
+            "table.csv:{}: expected at least the name, tag, and code fields",
+            line_index + 1
+        );
+        let code_str = &field_v[code_index];
+        let code = code_str
+            .strip_prefix("0x")
+            .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+            .unwrap_or_else(|| panic!("table.csv:{}: invalid code {:?}", line_index + 1, code_str));
+        assert!(
+            row_v.iter().all(|(_, _, row_code)| *row_code != code),
+            "table.csv:{}: duplicate code 0x{:02x}",
+            line_index + 1,
+            code
+        );
+        let tag = std::mem::take(&mut field_v[tag_index]);
+        let name = std::mem::take(&mut field_v[name_index]);
+        row_v.push((name, tag, code));
+    }
+    row_v
+}
+
+/// Splits a line of the multicodec table into its trimmed fields.  Fields may be double-quoted (the
+/// descriptions sometimes contain commas), in which case the quotes are removed and `""` is an
+/// escaped quote.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut field_v = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut char_iter = line.chars().peekable();
+    while let Some(c) = char_iter.next() {
+        match c {
+            '"' if in_quotes && char_iter.peek() == Some(&'"') => {
+                field.push('"');
+                char_iter.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => field_v.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(c),
+        }
+    }
+    field_v.push(field.trim().to_string());
+    field_v
+}
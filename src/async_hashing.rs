@@ -0,0 +1,300 @@
+use crate::{Base, Hasher, MBHash, MBHashStr, Result, hashing_io::VerifyState};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// The buffer size used by `hash_futures_reader` when reading from the reader.
+#[cfg(feature = "futures")]
+const FUTURES_READ_BUFFER_SIZE: usize = 1 << 16;
+
+/// Reads all of the given tokio async reader's content and computes its hash using the hash function
+/// given by the codec, returning it as an MBHash in the given base.  See also `hash_futures_reader`.
+#[cfg(feature = "async")]
+pub async fn hash_async_reader<R: tokio::io::AsyncRead + Unpin>(
+    mut reader: R,
+    codec: u64,
+    base: Base,
+) -> Result<MBHash> {
+    let mut sink = AsyncHashingSink::new(codec)?;
+    tokio::io::copy(&mut reader, &mut sink).await?;
+    Ok(sink.finalize(base))
+}
+
+/// Reads all of the given `futures_io::AsyncRead` reader's content (e.g. from async-std or smol) and
+/// computes its hash using the hash function given by the codec, returning it as an MBHash in the
+/// given base.  This is the `futures` counterpart of `hash_async_reader`.
+#[cfg(feature = "futures")]
+pub async fn hash_futures_reader<R: futures_io::AsyncRead + Unpin>(
+    mut reader: R,
+    codec: u64,
+    base: Base,
+) -> Result<MBHash> {
+    let mut hasher = Hasher::new(codec)?;
+    let mut buffer = vec![0u8; FUTURES_READ_BUFFER_SIZE];
+    loop {
+        let read_r =
+            std::future::poll_fn(|cx| Pin::new(&mut reader).poll_read(cx, &mut buffer)).await;
+        let n = match read_r {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        hasher.update(&buffer[..n]);
+    }
+    Ok(hasher.finalize(base))
+}
+
+/// An async sink that feeds everything written to it into a hasher, e.g. for use with
+/// `tokio::io::copy` or `futures::io::copy` while a request body streams in.  It implements tokio's
+/// `AsyncWrite` if the `async` feature is enabled, and `futures_io::AsyncWrite` if the `futures`
+/// feature is enabled.  See also `hash_async_reader` and `hash_futures_reader`.
+#[derive(Clone)]
+pub struct AsyncHashingSink {
+    hasher: Hasher,
+}
+
+impl AsyncHashingSink {
+    /// Creates a new sink using the hash function given by the codec.
+    pub fn new(codec: u64) -> Result<Self> {
+        Ok(Self {
+            hasher: Hasher::new(codec)?,
+        })
+    }
+    /// Computes the hash of everything written to this sink and returns it as an MBHash in the given base.
+    pub fn finalize(self, base: Base) -> MBHash {
+        self.hasher.finalize(base)
+    }
+}
+
+#[cfg(feature = "async")]
+impl tokio::io::AsyncWrite for AsyncHashingSink {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.get_mut().hasher.update(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "futures")]
+impl futures_io::AsyncWrite for AsyncHashingSink {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.get_mut().hasher.update(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// An async reader wrapper that passes through the inner reader's content while hashing it, and
+/// which fails with `std::io::ErrorKind::InvalidData` at EOF if the content does not match the
+/// expected MBHash.  It implements tokio's `AsyncRead` if the `async` feature is enabled, and
+/// `futures_io::AsyncRead` if the `futures` feature is enabled, in each case for inner readers
+/// implementing the same trait.  Note that the content is passed through before it is verified, so
+/// the consumer must not act on it until EOF has been reached without error.
+pub struct AsyncVerifyingReader<R> {
+    reader: R,
+    verify_state: VerifyState,
+    expected_digest: Vec<u8>,
+}
+
+impl<R> AsyncVerifyingReader<R> {
+    /// Creates a new verifying reader, using the hash function given by the codec of the expected MBHash.
+    pub fn new(reader: R, expected: &MBHashStr) -> Result<Self> {
+        let multihash = expected.decoded::<64>()?;
+        Ok(Self {
+            reader,
+            verify_state: VerifyState::Hashing(Hasher::new(multihash.code())?),
+            expected_digest: multihash.digest().to_vec(),
+        })
+    }
+    /// Returns the inner reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+    /// Hashes the bytes just read, or verifies the hash if no bytes were read even though there was
+    /// room for them, i.e. at EOF.
+    fn handle_read(&mut self, new_bytes: &[u8], buf_had_room: bool) -> std::io::Result<()> {
+        if !new_bytes.is_empty() {
+            self.verify_state.update(new_bytes);
+        } else if buf_had_room {
+            self.verify_state.verify_at_eof(&self.expected_digest)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: tokio::io::AsyncRead + Unpin> tokio::io::AsyncRead for AsyncVerifyingReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        let filled_len_before = buf.filled().len();
+        let buf_had_room = buf.remaining() > 0;
+        std::task::ready!(Pin::new(&mut this.reader).poll_read(cx, buf))?;
+        this.handle_read(&buf.filled()[filled_len_before..], buf_had_room)?;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<R: futures_io::AsyncRead + Unpin> futures_io::AsyncRead for AsyncVerifyingReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        let n = std::task::ready!(Pin::new(&mut this.reader).poll_read(cx, buf))?;
+        this.handle_read(&buf[..n], !buf.is_empty())?;
+        Poll::Ready(Ok(n))
+    }
+}
+
+#[cfg(all(test, feature = "blake3"))]
+mod tests {
+    use crate::{AsyncVerifyingReader, Base, Hasher};
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_hash_async_reader() {
+        use crate::hash_async_reader;
+
+        let content = b"HIPPO".repeat(10000);
+        let mb_hash =
+            hash_async_reader(content.as_slice(), ssi_multicodec::BLAKE3, Base::Base64Url)
+                .await
+                .expect("pass");
+        let mut hasher = Hasher::new(ssi_multicodec::BLAKE3).expect("pass");
+        hasher.update(&content);
+        assert_eq!(mb_hash, hasher.finalize(Base::Base64Url));
+
+        assert!(
+            hash_async_reader(
+                content.as_slice(),
+                ssi_multicodec::ED25519_PUB,
+                Base::Base64Url
+            )
+            .await
+            .is_err()
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_verifying_reader() {
+        use crate::hash_async_reader;
+        use tokio::io::AsyncReadExt;
+
+        let content = b"HIPPO".repeat(10000);
+        let mb_hash =
+            hash_async_reader(content.as_slice(), ssi_multicodec::BLAKE3, Base::Base58Btc)
+                .await
+                .expect("pass");
+
+        let mut reader = AsyncVerifyingReader::new(content.as_slice(), &mb_hash).expect("pass");
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).await.expect("pass");
+        assert_eq!(output, content);
+        assert_eq!(reader.read(&mut [0u8; 16]).await.expect("pass"), 0);
+
+        let tampered_content = b"OSTRICH".repeat(10000);
+        let mut reader =
+            AsyncVerifyingReader::new(tampered_content.as_slice(), &mb_hash).expect("pass");
+        let mut output = Vec::new();
+        let err = reader.read_to_end(&mut output).await.expect_err("pass");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        // Reading again at EOF must still fail, rather than reporting a clean EOF.
+        for _ in 0..2 {
+            let err = reader.read(&mut [0u8; 16]).await.expect_err("pass");
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn test_hash_futures_reader() {
+        use crate::{AsyncHashingSink, hash_futures_reader};
+
+        let content = b"HIPPO".repeat(10000);
+        let mut hasher = Hasher::new(ssi_multicodec::BLAKE3).expect("pass");
+        hasher.update(&content);
+        let expected = hasher.finalize(Base::Base64Url);
+
+        let mb_hash = futures::executor::block_on(hash_futures_reader(
+            content.as_slice(),
+            ssi_multicodec::BLAKE3,
+            Base::Base64Url,
+        ))
+        .expect("pass");
+        assert_eq!(mb_hash, expected);
+
+        let mut sink = AsyncHashingSink::new(ssi_multicodec::BLAKE3).expect("pass");
+        futures::executor::block_on(futures::io::copy(content.as_slice(), &mut sink))
+            .expect("pass");
+        assert_eq!(sink.finalize(Base::Base64Url), expected);
+
+        assert!(
+            futures::executor::block_on(hash_futures_reader(
+                content.as_slice(),
+                ssi_multicodec::ED25519_PUB,
+                Base::Base64Url
+            ))
+            .is_err()
+        );
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn test_futures_verifying_reader() {
+        use crate::hash_futures_reader;
+        use futures::io::AsyncReadExt;
+
+        futures::executor::block_on(async {
+            let content = b"HIPPO".repeat(10000);
+            let mb_hash =
+                hash_futures_reader(content.as_slice(), ssi_multicodec::BLAKE3, Base::Base58Btc)
+                    .await
+                    .expect("pass");
+
+            let mut reader = AsyncVerifyingReader::new(content.as_slice(), &mb_hash).expect("pass");
+            let mut output = Vec::new();
+            reader.read_to_end(&mut output).await.expect("pass");
+            assert_eq!(output, content);
+            assert_eq!(reader.read(&mut [0u8; 16]).await.expect("pass"), 0);
+
+            let tampered_content = b"OSTRICH".repeat(10000);
+            let mut reader =
+                AsyncVerifyingReader::new(tampered_content.as_slice(), &mb_hash).expect("pass");
+            let mut output = Vec::new();
+            let err = reader.read_to_end(&mut output).await.expect_err("pass");
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+            // Reading again at EOF must still fail, rather than reporting a clean EOF.
+            for _ in 0..2 {
+                let err = reader.read(&mut [0u8; 16]).await.expect_err("pass");
+                assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+            }
+        });
+    }
+}
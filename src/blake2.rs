@@ -0,0 +1,293 @@
+use crate::{Base, ErrorKind, MBHash, MBHashStr, Result, bail, ensure};
+use blake2::digest::{
+    VariableOutput,
+    array::ArraySize,
+    consts::{U32, U64},
+    typenum::{IsLessOrEqual, True},
+};
+
+/// Returns the multihash codec for BLAKE2b with the given output length (in bytes), which must be
+/// in the range 1..=64.  The codecs BLAKE2B_8 through BLAKE2B_512 are contiguous, one per output byte.
+pub(crate) fn blake2b_codec(output_len: usize) -> Result<u64> {
+    if !(1..=64).contains(&output_len) {
+        bail!(
+            "BLAKE2b output length must be in the range 1..=64 bytes, but got {}",
+            output_len
+        );
+    }
+    Ok(ssi_multicodec::BLAKE2B_8 + (output_len as u64 - 1))
+}
+
+/// Returns the multihash codec for BLAKE2s with the given output length (in bytes), which must be
+/// in the range 1..=32.  The codecs BLAKE2S_8 through BLAKE2S_256 are contiguous, one per output byte.
+pub(crate) fn blake2s_codec(output_len: usize) -> Result<u64> {
+    if !(1..=32).contains(&output_len) {
+        bail!(
+            "BLAKE2s output length must be in the range 1..=32 bytes, but got {}",
+            output_len
+        );
+    }
+    Ok(ssi_multicodec::BLAKE2S_8 + (output_len as u64 - 1))
+}
+
+/// Returns the output length (in bytes) of the BLAKE2b codec, or `None` if the codec isn't one of
+/// BLAKE2B_8 through BLAKE2B_512.  This is the inverse of `blake2b_codec`.
+pub(crate) fn blake2b_output_len(codec: u64) -> Option<usize> {
+    (ssi_multicodec::BLAKE2B_8..=ssi_multicodec::BLAKE2B_512)
+        .contains(&codec)
+        .then(|| (codec - ssi_multicodec::BLAKE2B_8) as usize + 1)
+}
+
+/// Returns the output length (in bytes) of the BLAKE2s codec, or `None` if the codec isn't one of
+/// BLAKE2S_8 through BLAKE2S_256.  This is the inverse of `blake2s_codec`.
+pub(crate) fn blake2s_output_len(codec: u64) -> Option<usize> {
+    (ssi_multicodec::BLAKE2S_8..=ssi_multicodec::BLAKE2S_256)
+        .contains(&codec)
+        .then(|| (codec - ssi_multicodec::BLAKE2S_8) as usize + 1)
+}
+
+/// Computes the hash of a runtime-length hasher (e.g. `blake2::Blake2bVar`), whose length is its
+/// output size.
+pub(crate) fn finalize_variable(hasher: impl VariableOutput) -> Vec<u8> {
+    let mut hash = vec![0u8; hasher.output_size()];
+    hasher
+        .finalize_variable(&mut hash)
+        .expect("programmer error");
+    hash
+}
+
+impl MBHash {
+    /// Construct an MBHash from a BLAKE2b hash value.  The output length of the hasher (given by
+    /// `OutSize`, e.g. 32 bytes for `blake2::Blake2b256`) determines the codec, e.g. BLAKE2B_256.
+    pub fn from_blake2b<OutSize>(base: Base, hasher: blake2::Blake2b<OutSize>) -> Self
+    where
+        OutSize: ArraySize + IsLessOrEqual<U64, Output = True>,
+    {
+        use blake2::Digest;
+        let hash = hasher.finalize();
+        let codec = blake2b_codec(hash.len()).expect("programmer error");
+        MBHash::encoded::<64>(base, codec, &hash).unwrap()
+    }
+    /// Construct an MBHash from a BLAKE2s hash value.  The output length of the hasher (given by
+    /// `OutSize`, e.g. 32 bytes for `blake2::Blake2s256`) determines the codec, e.g. BLAKE2S_256.
+    pub fn from_blake2s<OutSize>(base: Base, hasher: blake2::Blake2s<OutSize>) -> Self
+    where
+        OutSize: ArraySize + IsLessOrEqual<U32, Output = True>,
+    {
+        use blake2::Digest;
+        let hash = hasher.finalize();
+        let codec = blake2s_codec(hash.len()).expect("programmer error");
+        MBHash::encoded::<32>(base, codec, &hash).unwrap()
+    }
+    /// Construct an MBHash from a BLAKE2b hash value whose output length is chosen at runtime.  The
+    /// output length of the hasher (which `blake2::Blake2bVar::new` restricts to 1..=64 bytes)
+    /// determines the codec, e.g. BLAKE2B_160 for 20 bytes.
+    pub fn from_blake2b_var(base: Base, hasher: blake2::Blake2bVar) -> Self {
+        let codec = blake2b_codec(hasher.output_size()).expect("programmer error");
+        let hash = finalize_variable(hasher);
+        MBHash::encoded::<64>(base, codec, &hash).unwrap()
+    }
+    /// Construct an MBHash from a BLAKE2s hash value whose output length is chosen at runtime.  The
+    /// output length of the hasher (which `blake2::Blake2sVar::new` restricts to 1..=32 bytes)
+    /// determines the codec, e.g. BLAKE2S_160 for 20 bytes.
+    pub fn from_blake2s_var(base: Base, hasher: blake2::Blake2sVar) -> Self {
+        let codec = blake2s_codec(hasher.output_size()).expect("programmer error");
+        let hash = finalize_variable(hasher);
+        MBHash::encoded::<32>(base, codec, &hash).unwrap()
+    }
+}
+
+impl MBHashStr {
+    /// Verifies that this MBHashStr is the BLAKE2b hash of the content fed into the given hasher.
+    /// The codec of this MBHashStr must match the output length of the hasher.
+    pub fn verify_blake2b<OutSize>(&self, hasher: blake2::Blake2b<OutSize>) -> Result<()>
+    where
+        OutSize: ArraySize + IsLessOrEqual<U64, Output = True>,
+    {
+        use blake2::Digest;
+        let hash = hasher.finalize();
+        self.verify_digest(blake2b_codec(hash.len())?, &hash)
+    }
+    /// Verifies that this MBHashStr is the BLAKE2s hash of the content fed into the given hasher.
+    /// The codec of this MBHashStr must match the output length of the hasher.
+    pub fn verify_blake2s<OutSize>(&self, hasher: blake2::Blake2s<OutSize>) -> Result<()>
+    where
+        OutSize: ArraySize + IsLessOrEqual<U32, Output = True>,
+    {
+        use blake2::Digest;
+        let hash = hasher.finalize();
+        self.verify_digest(blake2s_codec(hash.len())?, &hash)
+    }
+    /// Verifies that this MBHashStr is the BLAKE2b hash of the content fed into the given
+    /// runtime-length hasher.  The codec of this MBHashStr must match the output length of the hasher.
+    pub fn verify_blake2b_var(&self, hasher: blake2::Blake2bVar) -> Result<()> {
+        let codec = blake2b_codec(hasher.output_size())?;
+        self.verify_digest(codec, &finalize_variable(hasher))
+    }
+    /// Verifies that this MBHashStr is the BLAKE2s hash of the content fed into the given
+    /// runtime-length hasher.  The codec of this MBHashStr must match the output length of the hasher.
+    pub fn verify_blake2s_var(&self, hasher: blake2::Blake2sVar) -> Result<()> {
+        let codec = blake2s_codec(hasher.output_size())?;
+        self.verify_digest(codec, &finalize_variable(hasher))
+    }
+    fn verify_digest(&self, expected_codec: u64, digest_bytes: &[u8]) -> Result<()> {
+        let multihash = self.decoded::<64>()?;
+        ensure!(
+            multihash.code() == expected_codec,
+            kind: ErrorKind::WrongCodec {
+                expected: expected_codec,
+                actual: multihash.code(),
+            },
+            "expected codec 0x{:02x} but MBHash has codec 0x{:02x}",
+            expected_codec,
+            multihash.code()
+        );
+        ensure!(
+            multihash.digest() == digest_bytes,
+            kind: ErrorKind::VerificationFailed,
+            "MBHash digest does not match the computed digest"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Base, MBHash};
+
+    #[test]
+    fn test_blake2_codec() {
+        assert_eq!(
+            super::blake2b_codec(32).expect("pass"),
+            ssi_multicodec::BLAKE2B_256
+        );
+        assert_eq!(
+            super::blake2b_codec(64).expect("pass"),
+            ssi_multicodec::BLAKE2B_512
+        );
+        assert_eq!(
+            super::blake2s_codec(32).expect("pass"),
+            ssi_multicodec::BLAKE2S_256
+        );
+        assert!(super::blake2b_codec(0).is_err());
+        assert!(super::blake2b_codec(65).is_err());
+        assert!(super::blake2s_codec(33).is_err());
+
+        for output_len in 1..=64 {
+            let codec = super::blake2b_codec(output_len).expect("pass");
+            assert_eq!(super::blake2b_output_len(codec), Some(output_len));
+        }
+        for output_len in 1..=32 {
+            let codec = super::blake2s_codec(output_len).expect("pass");
+            assert_eq!(super::blake2s_output_len(codec), Some(output_len));
+        }
+        assert_eq!(
+            super::blake2b_output_len(ssi_multicodec::BLAKE2B_8 - 1),
+            None
+        );
+        assert_eq!(super::blake2b_output_len(ssi_multicodec::BLAKE2S_8), None);
+        assert_eq!(super::blake2s_output_len(ssi_multicodec::BLAKE2B_512), None);
+        assert_eq!(
+            super::blake2s_output_len(ssi_multicodec::BLAKE2S_256 + 1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_blake2_var() {
+        use blake2::digest::{Digest, Update, VariableOutput, consts::U20};
+
+        // A non-power-of-two output length agrees with the compile-time-length hasher.
+        let mut hasher = blake2::Blake2bVar::new(20).expect("pass");
+        Update::update(&mut hasher, b"HIPPO");
+        let mb_hash = MBHash::from_blake2b_var(Base::Base58Btc, hasher.clone());
+        println!("blake2b_160; mb_hash: {:?}", mb_hash);
+        let multihash = mb_hash.decoded::<64>().expect("pass");
+        assert_eq!(multihash.code(), ssi_multicodec::BLAKE2B_160);
+        assert_eq!(multihash.digest().len(), 20);
+        assert_eq!(
+            mb_hash,
+            MBHash::from_blake2b(
+                Base::Base58Btc,
+                blake2::Blake2b::<U20>::new_with_prefix(b"HIPPO")
+            )
+        );
+        mb_hash.verify_blake2b_var(hasher).expect("pass");
+
+        // Wrong output length, and therefore wrong codec.
+        let mut hasher = blake2::Blake2bVar::new(21).expect("pass");
+        Update::update(&mut hasher, b"HIPPO");
+        assert!(mb_hash.verify_blake2b_var(hasher).is_err());
+
+        let mut hasher = blake2::Blake2sVar::new(20).expect("pass");
+        Update::update(&mut hasher, b"HIPPO");
+        let mb_hash = MBHash::from_blake2s_var(Base::Base58Btc, hasher.clone());
+        println!("blake2s_160; mb_hash: {:?}", mb_hash);
+        assert_eq!(
+            mb_hash.decoded::<64>().expect("pass").code(),
+            ssi_multicodec::BLAKE2S_160
+        );
+        assert_eq!(
+            mb_hash,
+            MBHash::from_blake2s(
+                Base::Base58Btc,
+                blake2::Blake2s::<U20>::new_with_prefix(b"HIPPO")
+            )
+        );
+        mb_hash.verify_blake2s_var(hasher).expect("pass");
+    }
+
+    #[test]
+    fn test_blake2b_256_empty_input() {
+        let mb_hash = MBHash::from_blake2b(Base::Base16Lower, blake2::Blake2b256::default());
+        let multihash = mb_hash.decoded::<64>().expect("pass");
+        assert_eq!(multihash.code(), ssi_multicodec::BLAKE2B_256);
+        assert_eq!(
+            multihash.digest(),
+            hex_literal::hex!("0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8")
+        );
+    }
+
+    #[test]
+    fn test_blake2s_256_empty_input() {
+        let mb_hash = MBHash::from_blake2s(Base::Base16Lower, blake2::Blake2s256::default());
+        let multihash = mb_hash.decoded::<64>().expect("pass");
+        assert_eq!(multihash.code(), ssi_multicodec::BLAKE2S_256);
+        assert_eq!(
+            multihash.digest(),
+            hex_literal::hex!("69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9")
+        );
+    }
+
+    fn test_blake2_verify_case(base: Base) {
+        use blake2::Digest;
+        let mut hasher = blake2::Blake2b512::new();
+        hasher.update(b"HIPPO");
+        let mb_hash = MBHash::from_blake2b(base, hasher.clone());
+        println!("blake2b_512; base: {:?}, mb_hash: {:?}", base, mb_hash);
+        mb_hash.verify_blake2b(hasher).expect("pass");
+
+        // Wrong content.
+        let mut hasher = blake2::Blake2b512::new();
+        hasher.update(b"OSTRICH");
+        assert!(mb_hash.verify_blake2b(hasher).is_err());
+
+        // Wrong output length, and therefore wrong codec.
+        let mut hasher = blake2::Blake2b256::new();
+        hasher.update(b"HIPPO");
+        assert!(mb_hash.verify_blake2b(hasher).is_err());
+
+        let mut hasher = blake2::Blake2s256::new();
+        hasher.update(b"HIPPO");
+        let mb_hash = MBHash::from_blake2s(base, hasher.clone());
+        println!("blake2s_256; base: {:?}, mb_hash: {:?}", base, mb_hash);
+        mb_hash.verify_blake2s(hasher).expect("pass");
+    }
+
+    #[test]
+    fn test_blake2_verify() {
+        for base in [Base::Base32Lower, Base::Base58Btc, Base::Base64Url] {
+            test_blake2_verify_case(base);
+        }
+    }
+}
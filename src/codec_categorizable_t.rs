@@ -1,11 +1,46 @@
-use crate::CodecCategory;
+use crate::{CodecCategory, CodecName, ErrorKind, Result, codec_info, ensure};
 
 /// A trait that is used to define the sigil for a codec category within generics.
-/// Provides a method to get the codec category as an enum.
+/// Provides a method to get the codec category as an enum, as well as the validation that
+/// `MBXStr<Self>` applies, which can be overridden to define custom categories.
 pub trait CodecCategorizableT: Clone {
     fn codec_category() -> CodecCategory;
+    /// Returns true if the codec is allowed in this category.  The default is to accept exactly the
+    /// codecs that `CodecCategory::from_codec` puts in `Self::codec_category()`.
+    fn accepts_codec(codec: u64) -> bool {
+        CodecCategory::from_codec(codec) == Self::codec_category()
+    }
+    /// Validates the bytes following the varint-encoded codec, given that the codec was accepted by
+    /// `accepts_codec`.  The default is to check the byte length against the expected length in
+    /// `codec_info`, if there is one.
+    fn validate_codec_data(codec: u64, data: &[u8]) -> Result<()> {
+        if let Some(expected_byte_len) =
+            codec_info(codec).and_then(|codec_info| codec_info.expected_len_o)
+        {
+            ensure!(
+                data.len() == expected_byte_len,
+                kind: ErrorKind::WrongByteLength {
+                    codec,
+                    expected: expected_byte_len,
+                    actual: data.len(),
+                },
+                "codec {} expected {} bytes but got {}",
+                CodecName(codec),
+                expected_byte_len,
+                data.len()
+            );
+        }
+        Ok(())
+    }
 }
 
+/// The codec category sigil that accepts any codec and doesn't validate the bytes following it, so
+/// that `MBXStr<AnyCategory>` (i.e. `MBAnyStr`) is any well-formed `multibase(base, varint(codec) || bytes)`.
+/// This is useful for forwarding values (e.g. multikeys of unknown types) without interpreting them.
+/// Its `codec_category` is `CodecCategory::Unspecified`, but that isn't used for validation.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AnyCategory;
+
 /// The codec category sigil for private keys.
 /// See <https://github.com/multiformats/multicodec/blob/master/table.csv> for specific codecs.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -18,7 +53,6 @@ pub struct PubKeyCategory;
 
 /// The codec category sigil for signatures.
 /// See <https://github.com/multiformats/multicodec/blob/master/table.csv> for specific codecs.
-#[allow(dead_code)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct SignatureCategory;
 
@@ -28,6 +62,18 @@ pub struct SignatureCategory;
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct SymmetricKeyCategory;
 
+impl CodecCategorizableT for AnyCategory {
+    fn codec_category() -> CodecCategory {
+        CodecCategory::Unspecified
+    }
+    fn accepts_codec(_codec: u64) -> bool {
+        true
+    }
+    fn validate_codec_data(_codec: u64, _data: &[u8]) -> Result<()> {
+        Ok(())
+    }
+}
+
 impl CodecCategorizableT for PrivKeyCategory {
     fn codec_category() -> CodecCategory {
         CodecCategory::PrivKey
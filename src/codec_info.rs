@@ -0,0 +1,602 @@
+use crate::CodecCategory;
+
+/// Metadata about a codec from the multicodec table (see <https://github.com/multiformats/multicodec/blob/master/table.csv>),
+/// along with this crate's opinions about it, such as its `CodecCategory`.  See `codec_info`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CodecInfo {
+    /// The codec value.
+    pub code: u64,
+    /// The canonical name in the multicodec table, e.g. `ed25519-pub`.
+    pub name: &'static str,
+    /// The name of the corresponding constant in the `ssi_multicodec` crate, e.g. `ED25519_PUB`.
+    pub const_name: &'static str,
+    /// The tag in the multicodec table, e.g. `key` or `multihash`.
+    pub tag: &'static str,
+    /// The category that this crate assigns to the codec.
+    pub category: CodecCategory,
+    /// The expected byte length of the key material following the varint-encoded codec, if it is
+    /// fixed and known.  This is only populated for key codecs.
+    pub expected_len_o: Option<usize>,
+    /// For a public key codec, the codec of the corresponding private key, and vice versa.
+    pub paired_codec_o: Option<u64>,
+}
+
+/// Returns the metadata for the given codec, or `None` if it's not one that this crate knows about.
+pub fn codec_info(codec: u64) -> Option<&'static CodecInfo> {
+    CODEC_INFO_V
+        .binary_search_by_key(&codec, |codec_info| codec_info.code)
+        .ok()
+        .map(|index| &CODEC_INFO_V[index])
+}
+
+/// Returns the metadata for all the codecs that this crate knows about, sorted by codec value.
+pub fn codec_info_table() -> &'static [CodecInfo] {
+    CODEC_INFO_V
+}
+
+/// A codec value that displays as its `ssi_multicodec` constant name along with its value, e.g.
+/// `ED25519_PUB (0xed)`, or just its value (e.g. `0x1234567`) if it's not a codec that this crate
+/// knows about.  This is intended for use in error and log messages, and doesn't depend on the
+/// `codec-str` feature.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CodecName(pub u64);
+
+impl std::fmt::Display for CodecName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match codec_info(self.0) {
+            Some(codec_info) => write!(f, "{} (0x{:02x})", codec_info.const_name, self.0),
+            None => write!(f, "0x{:02x}", self.0),
+        }
+    }
+}
+
+// This defines `static CODEC_INFO_V: &[CodecInfo]`, sorted by codec value (which `codec_info`
+// relies on), generated by build.rs from the vendored multicodec table (see codec-table/README.md).
+include!(concat!(env!("OUT_DIR"), "/codec_info_table.rs"));
+
+#[cfg(test)]
+mod tests {
+    use crate::{CodecCategory, CodecName, codec_info, codec_info_table};
+
+    #[test]
+    fn test_codec_info_table_is_sorted() {
+        for pair in codec_info_table().windows(2) {
+            assert!(
+                pair[0].code < pair[1].code,
+                "{} (0x{:02x}) must come before {} (0x{:02x})",
+                pair[0].const_name,
+                pair[0].code,
+                pair[1].const_name,
+                pair[1].code
+            );
+        }
+    }
+
+    #[test]
+    fn test_codec_info() {
+        let ed25519_pub = codec_info(ssi_multicodec::ED25519_PUB).expect("pass");
+        println!("ed25519_pub: {:?}", ed25519_pub);
+        assert_eq!(ed25519_pub.name, "ed25519-pub");
+        assert_eq!(ed25519_pub.const_name, "ED25519_PUB");
+        assert_eq!(ed25519_pub.tag, "key");
+        assert_eq!(ed25519_pub.category, CodecCategory::PubKey);
+        assert_eq!(ed25519_pub.expected_len_o, Some(32));
+        assert_eq!(
+            ed25519_pub.paired_codec_o,
+            Some(ssi_multicodec::ED25519_PRIV)
+        );
+
+        let sha2_256 = codec_info(ssi_multicodec::SHA2_256).expect("pass");
+        assert_eq!(sha2_256.name, "sha2-256");
+        assert_eq!(sha2_256.category, CodecCategory::Multihash);
+
+        assert_eq!(
+            codec_info(crate::ED448_PRIV_CODEC).expect("pass").category,
+            CodecCategory::PrivKey
+        );
+        assert!(codec_info(0x1304).is_none());
+    }
+
+    #[test]
+    fn test_codec_info_paired_codecs_are_symmetric() {
+        for codec_info_entry in codec_info_table() {
+            if let Some(paired_codec) = codec_info_entry.paired_codec_o {
+                let paired = codec_info(paired_codec).expect("pass");
+                assert_eq!(
+                    paired.paired_codec_o,
+                    Some(codec_info_entry.code),
+                    "{}",
+                    codec_info_entry.name
+                );
+                assert_ne!(paired.category, codec_info_entry.category);
+            }
+        }
+    }
+
+    #[test]
+    fn test_codec_name() {
+        assert_eq!(
+            CodecName(ssi_multicodec::ED25519_PUB).to_string(),
+            "ED25519_PUB (0xed)"
+        );
+        assert_eq!(
+            CodecName(crate::ED448_PRIV_CODEC).to_string(),
+            "ED448_PRIV (0x1311)"
+        );
+        assert_eq!(CodecName(0x1234567).to_string(), "0x1234567");
+    }
+
+    /// The parser that build.rs uses to generate the table, so that this checks the same parse.
+    mod table_csv {
+        include!("../codec-table/table_csv.rs");
+
+        pub(super) fn extra_row_v() -> &'static [(&'static str, &'static str, u64)] {
+            EXTRA_ROW_V
+        }
+        pub(super) fn parse(table_csv: &str) -> Vec<(String, String, u64)> {
+            parse_table_csv(table_csv)
+        }
+    }
+
+    /// Checks that every row of the vendored multicodec table is in the generated table, consistent
+    /// with its name and tag.
+    #[test]
+    fn test_codec_info_matches_table_csv() {
+        // The rows with the `key` tag that are neither public, private, nor symmetric keys, and are
+        // therefore deliberately `CodecCategory::Unspecified`.  Any other `key` row must be
+        // categorized, so that a new kind of key in the multicodec table has to be looked at
+        // explicitly.  Every name listed here must be a row of table.csv.
+        const UNSPECIFIED_KEY_NAME_V: &[&str] = &[];
+
+        let row_v = table_csv::parse(include_str!("../codec-table/table.csv"));
+        for (name, tag, code) in &row_v {
+            let codec_info = codec_info(*code).expect("pass");
+            assert_eq!(codec_info.name, name);
+            assert_eq!(codec_info.tag, tag);
+            let expected_category_matches = match tag.as_str() {
+                "multihash" => codec_info.category == CodecCategory::Multihash,
+                "varsig" => codec_info.category == CodecCategory::Signature,
+                "key" if UNSPECIFIED_KEY_NAME_V.contains(&name.as_str()) => {
+                    codec_info.category == CodecCategory::Unspecified
+                }
+                "key" => matches!(
+                    codec_info.category,
+                    CodecCategory::PrivKey | CodecCategory::PubKey | CodecCategory::SymmetricKey
+                ),
+                _ => codec_info.category == CodecCategory::Unspecified,
+            };
+            assert!(
+                expected_category_matches,
+                "{} has tag {} but category {:?}",
+                name, tag, codec_info.category
+            );
+        }
+        for unspecified_key_name in UNSPECIFIED_KEY_NAME_V {
+            assert!(
+                row_v
+                    .iter()
+                    .any(|(name, tag, _)| name == unspecified_key_name && tag == "key"),
+                "{} is not a key row of table.csv",
+                unspecified_key_name
+            );
+        }
+
+        // The extra rows are added unless table.csv already has a row with the same name.
+        let mut extra_row_count = 0;
+        for (name, tag, code) in table_csv::extra_row_v() {
+            let codec_info = codec_info_table()
+                .iter()
+                .find(|codec_info| codec_info.name == *name)
+                .expect("pass");
+            assert_eq!(codec_info.tag, *tag);
+            if !row_v.iter().any(|(row_name, _, _)| row_name == name) {
+                assert_eq!(codec_info.code, *code);
+                extra_row_count += 1;
+            }
+        }
+        assert_eq!(codec_info_table().len(), row_v.len() + extra_row_count);
+    }
+
+    /// Checks the generated codec values and constant names against the ssi_multicodec crate.
+    #[test]
+    fn test_codec_info_matches_ssi_multicodec() {
+        macro_rules! ssi_multicodec_constant_v {
+            ($($const_name:ident),* $(,)?) => {
+                [$((ssi_multicodec::$const_name, stringify!($const_name))),*]
+            };
+        }
+        for (code, const_name) in ssi_multicodec_constant_v![
+            IDENTITY,
+            SHA1,
+            SHA2_256,
+            SHA2_512,
+            SHA3_512,
+            SHA3_384,
+            SHA3_256,
+            SHA3_224,
+            SHAKE_128,
+            SHAKE_256,
+            KECCAK_224,
+            KECCAK_256,
+            KECCAK_384,
+            KECCAK_512,
+            BLAKE3,
+            SHA2_384,
+            DBL_SHA2_256,
+            AES_128,
+            AES_192,
+            AES_256,
+            CHACHA_128,
+            CHACHA_256,
+            MD4,
+            MD5,
+            SECP256K1_PUB,
+            BLS12_381_G1_PUB,
+            BLS12_381_G2_PUB,
+            X25519_PUB,
+            ED25519_PUB,
+            BLS12_381_G1G2_PUB,
+            SR25519_PUB,
+            SHA2_256_TRUNC254_PADDED,
+            SHA2_224,
+            SHA2_512_224,
+            SHA2_512_256,
+            RIPEMD_128,
+            RIPEMD_160,
+            RIPEMD_256,
+            RIPEMD_320,
+            X11,
+            P256_PUB,
+            P384_PUB,
+            P521_PUB,
+            ED448_PUB,
+            X448_PUB,
+            RSA_PUB,
+            SM2_PUB,
+            ED25519_PRIV,
+            SECP256K1_PRIV,
+            X25519_PRIV,
+            SR25519_PRIV,
+            RSA_PRIV,
+            P256_PRIV,
+            P384_PRIV,
+            P521_PRIV,
+            KANGAROOTWELVE,
+            SM3_256,
+            SHA256A,
+            BLAKE2B_8,
+            BLAKE2B_16,
+            BLAKE2B_24,
+            BLAKE2B_32,
+            BLAKE2B_40,
+            BLAKE2B_48,
+            BLAKE2B_56,
+            BLAKE2B_64,
+            BLAKE2B_72,
+            BLAKE2B_80,
+            BLAKE2B_88,
+            BLAKE2B_96,
+            BLAKE2B_104,
+            BLAKE2B_112,
+            BLAKE2B_120,
+            BLAKE2B_128,
+            BLAKE2B_136,
+            BLAKE2B_144,
+            BLAKE2B_152,
+            BLAKE2B_160,
+            BLAKE2B_168,
+            BLAKE2B_176,
+            BLAKE2B_184,
+            BLAKE2B_192,
+            BLAKE2B_200,
+            BLAKE2B_208,
+            BLAKE2B_216,
+            BLAKE2B_224,
+            BLAKE2B_232,
+            BLAKE2B_240,
+            BLAKE2B_248,
+            BLAKE2B_256,
+            BLAKE2B_264,
+            BLAKE2B_272,
+            BLAKE2B_280,
+            BLAKE2B_288,
+            BLAKE2B_296,
+            BLAKE2B_304,
+            BLAKE2B_312,
+            BLAKE2B_320,
+            BLAKE2B_328,
+            BLAKE2B_336,
+            BLAKE2B_344,
+            BLAKE2B_352,
+            BLAKE2B_360,
+            BLAKE2B_368,
+            BLAKE2B_376,
+            BLAKE2B_384,
+            BLAKE2B_392,
+            BLAKE2B_400,
+            BLAKE2B_408,
+            BLAKE2B_416,
+            BLAKE2B_424,
+            BLAKE2B_432,
+            BLAKE2B_440,
+            BLAKE2B_448,
+            BLAKE2B_456,
+            BLAKE2B_464,
+            BLAKE2B_472,
+            BLAKE2B_480,
+            BLAKE2B_488,
+            BLAKE2B_496,
+            BLAKE2B_504,
+            BLAKE2B_512,
+            BLAKE2S_8,
+            BLAKE2S_16,
+            BLAKE2S_24,
+            BLAKE2S_32,
+            BLAKE2S_40,
+            BLAKE2S_48,
+            BLAKE2S_56,
+            BLAKE2S_64,
+            BLAKE2S_72,
+            BLAKE2S_80,
+            BLAKE2S_88,
+            BLAKE2S_96,
+            BLAKE2S_104,
+            BLAKE2S_112,
+            BLAKE2S_120,
+            BLAKE2S_128,
+            BLAKE2S_136,
+            BLAKE2S_144,
+            BLAKE2S_152,
+            BLAKE2S_160,
+            BLAKE2S_168,
+            BLAKE2S_176,
+            BLAKE2S_184,
+            BLAKE2S_192,
+            BLAKE2S_200,
+            BLAKE2S_208,
+            BLAKE2S_216,
+            BLAKE2S_224,
+            BLAKE2S_232,
+            BLAKE2S_240,
+            BLAKE2S_248,
+            BLAKE2S_256,
+            SKEIN256_8,
+            SKEIN256_16,
+            SKEIN256_24,
+            SKEIN256_32,
+            SKEIN256_40,
+            SKEIN256_48,
+            SKEIN256_56,
+            SKEIN256_64,
+            SKEIN256_72,
+            SKEIN256_80,
+            SKEIN256_88,
+            SKEIN256_96,
+            SKEIN256_104,
+            SKEIN256_112,
+            SKEIN256_120,
+            SKEIN256_128,
+            SKEIN256_136,
+            SKEIN256_144,
+            SKEIN256_152,
+            SKEIN256_160,
+            SKEIN256_168,
+            SKEIN256_176,
+            SKEIN256_184,
+            SKEIN256_192,
+            SKEIN256_200,
+            SKEIN256_208,
+            SKEIN256_216,
+            SKEIN256_224,
+            SKEIN256_232,
+            SKEIN256_240,
+            SKEIN256_248,
+            SKEIN256_256,
+            SKEIN512_8,
+            SKEIN512_16,
+            SKEIN512_24,
+            SKEIN512_32,
+            SKEIN512_40,
+            SKEIN512_48,
+            SKEIN512_56,
+            SKEIN512_64,
+            SKEIN512_72,
+            SKEIN512_80,
+            SKEIN512_88,
+            SKEIN512_96,
+            SKEIN512_104,
+            SKEIN512_112,
+            SKEIN512_120,
+            SKEIN512_128,
+            SKEIN512_136,
+            SKEIN512_144,
+            SKEIN512_152,
+            SKEIN512_160,
+            SKEIN512_168,
+            SKEIN512_176,
+            SKEIN512_184,
+            SKEIN512_192,
+            SKEIN512_200,
+            SKEIN512_208,
+            SKEIN512_216,
+            SKEIN512_224,
+            SKEIN512_232,
+            SKEIN512_240,
+            SKEIN512_248,
+            SKEIN512_256,
+            SKEIN512_264,
+            SKEIN512_272,
+            SKEIN512_280,
+            SKEIN512_288,
+            SKEIN512_296,
+            SKEIN512_304,
+            SKEIN512_312,
+            SKEIN512_320,
+            SKEIN512_328,
+            SKEIN512_336,
+            SKEIN512_344,
+            SKEIN512_352,
+            SKEIN512_360,
+            SKEIN512_368,
+            SKEIN512_376,
+            SKEIN512_384,
+            SKEIN512_392,
+            SKEIN512_400,
+            SKEIN512_408,
+            SKEIN512_416,
+            SKEIN512_424,
+            SKEIN512_432,
+            SKEIN512_440,
+            SKEIN512_448,
+            SKEIN512_456,
+            SKEIN512_464,
+            SKEIN512_472,
+            SKEIN512_480,
+            SKEIN512_488,
+            SKEIN512_496,
+            SKEIN512_504,
+            SKEIN512_512,
+            SKEIN1024_8,
+            SKEIN1024_16,
+            SKEIN1024_24,
+            SKEIN1024_32,
+            SKEIN1024_40,
+            SKEIN1024_48,
+            SKEIN1024_56,
+            SKEIN1024_64,
+            SKEIN1024_72,
+            SKEIN1024_80,
+            SKEIN1024_88,
+            SKEIN1024_96,
+            SKEIN1024_104,
+            SKEIN1024_112,
+            SKEIN1024_120,
+            SKEIN1024_128,
+            SKEIN1024_136,
+            SKEIN1024_144,
+            SKEIN1024_152,
+            SKEIN1024_160,
+            SKEIN1024_168,
+            SKEIN1024_176,
+            SKEIN1024_184,
+            SKEIN1024_192,
+            SKEIN1024_200,
+            SKEIN1024_208,
+            SKEIN1024_216,
+            SKEIN1024_224,
+            SKEIN1024_232,
+            SKEIN1024_240,
+            SKEIN1024_248,
+            SKEIN1024_256,
+            SKEIN1024_264,
+            SKEIN1024_272,
+            SKEIN1024_280,
+            SKEIN1024_288,
+            SKEIN1024_296,
+            SKEIN1024_304,
+            SKEIN1024_312,
+            SKEIN1024_320,
+            SKEIN1024_328,
+            SKEIN1024_336,
+            SKEIN1024_344,
+            SKEIN1024_352,
+            SKEIN1024_360,
+            SKEIN1024_368,
+            SKEIN1024_376,
+            SKEIN1024_384,
+            SKEIN1024_392,
+            SKEIN1024_400,
+            SKEIN1024_408,
+            SKEIN1024_416,
+            SKEIN1024_424,
+            SKEIN1024_432,
+            SKEIN1024_440,
+            SKEIN1024_448,
+            SKEIN1024_456,
+            SKEIN1024_464,
+            SKEIN1024_472,
+            SKEIN1024_480,
+            SKEIN1024_488,
+            SKEIN1024_496,
+            SKEIN1024_504,
+            SKEIN1024_512,
+            SKEIN1024_520,
+            SKEIN1024_528,
+            SKEIN1024_536,
+            SKEIN1024_544,
+            SKEIN1024_552,
+            SKEIN1024_560,
+            SKEIN1024_568,
+            SKEIN1024_576,
+            SKEIN1024_584,
+            SKEIN1024_592,
+            SKEIN1024_600,
+            SKEIN1024_608,
+            SKEIN1024_616,
+            SKEIN1024_624,
+            SKEIN1024_632,
+            SKEIN1024_640,
+            SKEIN1024_648,
+            SKEIN1024_656,
+            SKEIN1024_664,
+            SKEIN1024_672,
+            SKEIN1024_680,
+            SKEIN1024_688,
+            SKEIN1024_696,
+            SKEIN1024_704,
+            SKEIN1024_712,
+            SKEIN1024_720,
+            SKEIN1024_728,
+            SKEIN1024_736,
+            SKEIN1024_744,
+            SKEIN1024_752,
+            SKEIN1024_760,
+            SKEIN1024_768,
+            SKEIN1024_776,
+            SKEIN1024_784,
+            SKEIN1024_792,
+            SKEIN1024_800,
+            SKEIN1024_808,
+            SKEIN1024_816,
+            SKEIN1024_824,
+            SKEIN1024_832,
+            SKEIN1024_840,
+            SKEIN1024_848,
+            SKEIN1024_856,
+            SKEIN1024_864,
+            SKEIN1024_872,
+            SKEIN1024_880,
+            SKEIN1024_888,
+            SKEIN1024_896,
+            SKEIN1024_904,
+            SKEIN1024_912,
+            SKEIN1024_920,
+            SKEIN1024_928,
+            SKEIN1024_936,
+            SKEIN1024_944,
+            SKEIN1024_952,
+            SKEIN1024_960,
+            SKEIN1024_968,
+            SKEIN1024_976,
+            SKEIN1024_984,
+            SKEIN1024_992,
+            SKEIN1024_1000,
+            SKEIN1024_1008,
+            SKEIN1024_1016,
+            SKEIN1024_1024,
+            ES256K,
+            BLS_12381_G1_SIG,
+            BLS_12381_G2_SIG,
+            EDDSA,
+            EIP_191,
+            JWK_JCS_PUB,
+            ES256,
+            ES284,
+            ES512,
+            RS256,
+        ] {
+            let codec_info = codec_info(code).expect("pass");
+            assert_eq!(codec_info.const_name, const_name);
+        }
+    }
+}
@@ -1,408 +1,102 @@
-use crate::{Result, bail};
+use crate::{ErrorKind, Result, codec_info, codec_info_table, error};
 
+/// Returns the name of the `ssi_multicodec` constant for the given codec, e.g. `"ED25519_PUB"`.
+/// See `codec_info` for more metadata about the codec.
 pub fn codec_str(codec: u64) -> Result<&'static str> {
-    let s = match codec {
-        // Multihash category
-        ssi_multicodec::IDENTITY => "IDENTITY",
-        ssi_multicodec::SHA1 => "SHA1",
-        ssi_multicodec::SHA2_224 => "SHA2_224",
-        ssi_multicodec::SHA2_256 => "SHA2_256",
-        ssi_multicodec::SHA2_384 => "SHA2_384",
-        ssi_multicodec::SHA2_512 => "SHA2_512",
-        ssi_multicodec::SHA3_512 => "SHA3_512",
-        ssi_multicodec::SHA3_384 => "SHA3_384",
-        ssi_multicodec::SHA3_256 => "SHA3_256",
-        ssi_multicodec::SHA3_224 => "SHA3_224",
-        ssi_multicodec::SHAKE_128 => "SHAKE_128",
-        ssi_multicodec::SHAKE_256 => "SHAKE_256",
-        ssi_multicodec::KECCAK_224 => "KECCAK_224",
-        ssi_multicodec::KECCAK_256 => "KECCAK_256",
-        ssi_multicodec::KECCAK_384 => "KECCAK_384",
-        ssi_multicodec::KECCAK_512 => "KECCAK_512",
-        ssi_multicodec::BLAKE3 => "BLAKE3",
-        ssi_multicodec::DBL_SHA2_256 => "DBL_SHA2_256",
-        ssi_multicodec::MD4 => "MD4",
-        ssi_multicodec::MD5 => "MD5",
-        ssi_multicodec::SHA2_256_TRUNC254_PADDED => "SHA2_256_TRUNC254_PADDED",
-        ssi_multicodec::SHA2_512_224 => "SHA2_512_224",
-        ssi_multicodec::SHA2_512_256 => "SHA2_512_256",
-        ssi_multicodec::RIPEMD_128 => "RIPEMD_128",
-        ssi_multicodec::RIPEMD_160 => "RIPEMD_160",
-        ssi_multicodec::RIPEMD_256 => "RIPEMD_256",
-        ssi_multicodec::RIPEMD_320 => "RIPEMD_320",
-        ssi_multicodec::KANGAROOTWELVE => "KANGAROOTWELVE",
-        ssi_multicodec::SM3_256 => "SM3_256",
-        ssi_multicodec::SHA256A => "SHA256A",
-        ssi_multicodec::BLAKE2B_8 => "BLAKE2B_8",
-        ssi_multicodec::BLAKE2B_16 => "BLAKE2B_16",
-        ssi_multicodec::BLAKE2B_24 => "BLAKE2B_24",
-        ssi_multicodec::BLAKE2B_32 => "BLAKE2B_32",
-        ssi_multicodec::BLAKE2B_40 => "BLAKE2B_40",
-        ssi_multicodec::BLAKE2B_48 => "BLAKE2B_48",
-        ssi_multicodec::BLAKE2B_56 => "BLAKE2B_56",
-        ssi_multicodec::BLAKE2B_64 => "BLAKE2B_64",
-        ssi_multicodec::BLAKE2B_72 => "BLAKE2B_72",
-        ssi_multicodec::BLAKE2B_80 => "BLAKE2B_80",
-        ssi_multicodec::BLAKE2B_88 => "BLAKE2B_88",
-        ssi_multicodec::BLAKE2B_96 => "BLAKE2B_96",
-        ssi_multicodec::BLAKE2B_104 => "BLAKE2B_104",
-        ssi_multicodec::BLAKE2B_112 => "BLAKE2B_112",
-        ssi_multicodec::BLAKE2B_120 => "BLAKE2B_120",
-        ssi_multicodec::BLAKE2B_128 => "BLAKE2B_128",
-        ssi_multicodec::BLAKE2B_136 => "BLAKE2B_136",
-        ssi_multicodec::BLAKE2B_144 => "BLAKE2B_144",
-        ssi_multicodec::BLAKE2B_152 => "BLAKE2B_152",
-        ssi_multicodec::BLAKE2B_160 => "BLAKE2B_160",
-        ssi_multicodec::BLAKE2B_168 => "BLAKE2B_168",
-        ssi_multicodec::BLAKE2B_176 => "BLAKE2B_176",
-        ssi_multicodec::BLAKE2B_184 => "BLAKE2B_184",
-        ssi_multicodec::BLAKE2B_192 => "BLAKE2B_192",
-        ssi_multicodec::BLAKE2B_200 => "BLAKE2B_200",
-        ssi_multicodec::BLAKE2B_208 => "BLAKE2B_208",
-        ssi_multicodec::BLAKE2B_216 => "BLAKE2B_216",
-        ssi_multicodec::BLAKE2B_224 => "BLAKE2B_224",
-        ssi_multicodec::BLAKE2B_232 => "BLAKE2B_232",
-        ssi_multicodec::BLAKE2B_240 => "BLAKE2B_240",
-        ssi_multicodec::BLAKE2B_248 => "BLAKE2B_248",
-        ssi_multicodec::BLAKE2B_256 => "BLAKE2B_256",
-        ssi_multicodec::BLAKE2B_264 => "BLAKE2B_264",
-        ssi_multicodec::BLAKE2B_272 => "BLAKE2B_272",
-        ssi_multicodec::BLAKE2B_280 => "BLAKE2B_280",
-        ssi_multicodec::BLAKE2B_288 => "BLAKE2B_288",
-        ssi_multicodec::BLAKE2B_296 => "BLAKE2B_296",
-        ssi_multicodec::BLAKE2B_304 => "BLAKE2B_304",
-        ssi_multicodec::BLAKE2B_312 => "BLAKE2B_312",
-        ssi_multicodec::BLAKE2B_320 => "BLAKE2B_320",
-        ssi_multicodec::BLAKE2B_328 => "BLAKE2B_328",
-        ssi_multicodec::BLAKE2B_336 => "BLAKE2B_336",
-        ssi_multicodec::BLAKE2B_344 => "BLAKE2B_344",
-        ssi_multicodec::BLAKE2B_352 => "BLAKE2B_352",
-        ssi_multicodec::BLAKE2B_360 => "BLAKE2B_360",
-        ssi_multicodec::BLAKE2B_368 => "BLAKE2B_368",
-        ssi_multicodec::BLAKE2B_376 => "BLAKE2B_376",
-        ssi_multicodec::BLAKE2B_384 => "BLAKE2B_384",
-        ssi_multicodec::BLAKE2B_392 => "BLAKE2B_392",
-        ssi_multicodec::BLAKE2B_400 => "BLAKE2B_400",
-        ssi_multicodec::BLAKE2B_408 => "BLAKE2B_408",
-        ssi_multicodec::BLAKE2B_416 => "BLAKE2B_416",
-        ssi_multicodec::BLAKE2B_424 => "BLAKE2B_424",
-        ssi_multicodec::BLAKE2B_432 => "BLAKE2B_432",
-        ssi_multicodec::BLAKE2B_440 => "BLAKE2B_440",
-        ssi_multicodec::BLAKE2B_448 => "BLAKE2B_448",
-        ssi_multicodec::BLAKE2B_456 => "BLAKE2B_456",
-        ssi_multicodec::BLAKE2B_464 => "BLAKE2B_464",
-        ssi_multicodec::BLAKE2B_472 => "BLAKE2B_472",
-        ssi_multicodec::BLAKE2B_480 => "BLAKE2B_480",
-        ssi_multicodec::BLAKE2B_488 => "BLAKE2B_488",
-        ssi_multicodec::BLAKE2B_496 => "BLAKE2B_496",
-        ssi_multicodec::BLAKE2B_504 => "BLAKE2B_504",
-        ssi_multicodec::BLAKE2B_512 => "BLAKE2B_512",
-        ssi_multicodec::BLAKE2S_8 => "BLAKE2S_8",
-        ssi_multicodec::BLAKE2S_16 => "BLAKE2S_16",
-        ssi_multicodec::BLAKE2S_24 => "BLAKE2S_24",
-        ssi_multicodec::BLAKE2S_32 => "BLAKE2S_32",
-        ssi_multicodec::BLAKE2S_40 => "BLAKE2S_40",
-        ssi_multicodec::BLAKE2S_48 => "BLAKE2S_48",
-        ssi_multicodec::BLAKE2S_56 => "BLAKE2S_56",
-        ssi_multicodec::BLAKE2S_64 => "BLAKE2S_64",
-        ssi_multicodec::BLAKE2S_72 => "BLAKE2S_72",
-        ssi_multicodec::BLAKE2S_80 => "BLAKE2S_80",
-        ssi_multicodec::BLAKE2S_88 => "BLAKE2S_88",
-        ssi_multicodec::BLAKE2S_96 => "BLAKE2S_96",
-        ssi_multicodec::BLAKE2S_104 => "BLAKE2S_104",
-        ssi_multicodec::BLAKE2S_112 => "BLAKE2S_112",
-        ssi_multicodec::BLAKE2S_120 => "BLAKE2S_120",
-        ssi_multicodec::BLAKE2S_128 => "BLAKE2S_128",
-        ssi_multicodec::BLAKE2S_136 => "BLAKE2S_136",
-        ssi_multicodec::BLAKE2S_144 => "BLAKE2S_144",
-        ssi_multicodec::BLAKE2S_152 => "BLAKE2S_152",
-        ssi_multicodec::BLAKE2S_160 => "BLAKE2S_160",
-        ssi_multicodec::BLAKE2S_168 => "BLAKE2S_168",
-        ssi_multicodec::BLAKE2S_176 => "BLAKE2S_176",
-        ssi_multicodec::BLAKE2S_184 => "BLAKE2S_184",
-        ssi_multicodec::BLAKE2S_192 => "BLAKE2S_192",
-        ssi_multicodec::BLAKE2S_200 => "BLAKE2S_200",
-        ssi_multicodec::BLAKE2S_208 => "BLAKE2S_208",
-        ssi_multicodec::BLAKE2S_216 => "BLAKE2S_216",
-        ssi_multicodec::BLAKE2S_224 => "BLAKE2S_224",
-        ssi_multicodec::BLAKE2S_232 => "BLAKE2S_232",
-        ssi_multicodec::BLAKE2S_240 => "BLAKE2S_240",
-        ssi_multicodec::BLAKE2S_248 => "BLAKE2S_248",
-        ssi_multicodec::BLAKE2S_256 => "BLAKE2S_256",
-        ssi_multicodec::SKEIN256_8 => "SKEIN256_8",
-        ssi_multicodec::SKEIN256_16 => "SKEIN256_16",
-        ssi_multicodec::SKEIN256_24 => "SKEIN256_24",
-        ssi_multicodec::SKEIN256_32 => "SKEIN256_32",
-        ssi_multicodec::SKEIN256_40 => "SKEIN256_40",
-        ssi_multicodec::SKEIN256_48 => "SKEIN256_48",
-        ssi_multicodec::SKEIN256_56 => "SKEIN256_56",
-        ssi_multicodec::SKEIN256_64 => "SKEIN256_64",
-        ssi_multicodec::SKEIN256_72 => "SKEIN256_72",
-        ssi_multicodec::SKEIN256_80 => "SKEIN256_80",
-        ssi_multicodec::SKEIN256_88 => "SKEIN256_88",
-        ssi_multicodec::SKEIN256_96 => "SKEIN256_96",
-        ssi_multicodec::SKEIN256_104 => "SKEIN256_104",
-        ssi_multicodec::SKEIN256_112 => "SKEIN256_112",
-        ssi_multicodec::SKEIN256_120 => "SKEIN256_120",
-        ssi_multicodec::SKEIN256_128 => "SKEIN256_128",
-        ssi_multicodec::SKEIN256_136 => "SKEIN256_136",
-        ssi_multicodec::SKEIN256_144 => "SKEIN256_144",
-        ssi_multicodec::SKEIN256_152 => "SKEIN256_152",
-        ssi_multicodec::SKEIN256_160 => "SKEIN256_160",
-        ssi_multicodec::SKEIN256_168 => "SKEIN256_168",
-        ssi_multicodec::SKEIN256_176 => "SKEIN256_176",
-        ssi_multicodec::SKEIN256_184 => "SKEIN256_184",
-        ssi_multicodec::SKEIN256_192 => "SKEIN256_192",
-        ssi_multicodec::SKEIN256_200 => "SKEIN256_200",
-        ssi_multicodec::SKEIN256_208 => "SKEIN256_208",
-        ssi_multicodec::SKEIN256_216 => "SKEIN256_216",
-        ssi_multicodec::SKEIN256_224 => "SKEIN256_224",
-        ssi_multicodec::SKEIN256_232 => "SKEIN256_232",
-        ssi_multicodec::SKEIN256_240 => "SKEIN256_240",
-        ssi_multicodec::SKEIN256_248 => "SKEIN256_248",
-        ssi_multicodec::SKEIN256_256 => "SKEIN256_256",
-        ssi_multicodec::SKEIN512_8 => "SKEIN512_8",
-        ssi_multicodec::SKEIN512_16 => "SKEIN512_16",
-        ssi_multicodec::SKEIN512_24 => "SKEIN512_24",
-        ssi_multicodec::SKEIN512_32 => "SKEIN512_32",
-        ssi_multicodec::SKEIN512_40 => "SKEIN512_40",
-        ssi_multicodec::SKEIN512_48 => "SKEIN512_48",
-        ssi_multicodec::SKEIN512_56 => "SKEIN512_56",
-        ssi_multicodec::SKEIN512_64 => "SKEIN512_64",
-        ssi_multicodec::SKEIN512_72 => "SKEIN512_72",
-        ssi_multicodec::SKEIN512_80 => "SKEIN512_80",
-        ssi_multicodec::SKEIN512_88 => "SKEIN512_88",
-        ssi_multicodec::SKEIN512_96 => "SKEIN512_96",
-        ssi_multicodec::SKEIN512_104 => "SKEIN512_104",
-        ssi_multicodec::SKEIN512_112 => "SKEIN512_112",
-        ssi_multicodec::SKEIN512_120 => "SKEIN512_120",
-        ssi_multicodec::SKEIN512_128 => "SKEIN512_128",
-        ssi_multicodec::SKEIN512_136 => "SKEIN512_136",
-        ssi_multicodec::SKEIN512_144 => "SKEIN512_144",
-        ssi_multicodec::SKEIN512_152 => "SKEIN512_152",
-        ssi_multicodec::SKEIN512_160 => "SKEIN512_160",
-        ssi_multicodec::SKEIN512_168 => "SKEIN512_168",
-        ssi_multicodec::SKEIN512_176 => "SKEIN512_176",
-        ssi_multicodec::SKEIN512_184 => "SKEIN512_184",
-        ssi_multicodec::SKEIN512_192 => "SKEIN512_192",
-        ssi_multicodec::SKEIN512_200 => "SKEIN512_200",
-        ssi_multicodec::SKEIN512_208 => "SKEIN512_208",
-        ssi_multicodec::SKEIN512_216 => "SKEIN512_216",
-        ssi_multicodec::SKEIN512_224 => "SKEIN512_224",
-        ssi_multicodec::SKEIN512_232 => "SKEIN512_232",
-        ssi_multicodec::SKEIN512_240 => "SKEIN512_240",
-        ssi_multicodec::SKEIN512_248 => "SKEIN512_248",
-        ssi_multicodec::SKEIN512_256 => "SKEIN512_256",
-        ssi_multicodec::SKEIN512_264 => "SKEIN512_264",
-        ssi_multicodec::SKEIN512_272 => "SKEIN512_272",
-        ssi_multicodec::SKEIN512_280 => "SKEIN512_280",
-        ssi_multicodec::SKEIN512_288 => "SKEIN512_288",
-        ssi_multicodec::SKEIN512_296 => "SKEIN512_296",
-        ssi_multicodec::SKEIN512_304 => "SKEIN512_304",
-        ssi_multicodec::SKEIN512_312 => "SKEIN512_312",
-        ssi_multicodec::SKEIN512_320 => "SKEIN512_320",
-        ssi_multicodec::SKEIN512_328 => "SKEIN512_328",
-        ssi_multicodec::SKEIN512_336 => "SKEIN512_336",
-        ssi_multicodec::SKEIN512_344 => "SKEIN512_344",
-        ssi_multicodec::SKEIN512_352 => "SKEIN512_352",
-        ssi_multicodec::SKEIN512_360 => "SKEIN512_360",
-        ssi_multicodec::SKEIN512_368 => "SKEIN512_368",
-        ssi_multicodec::SKEIN512_376 => "SKEIN512_376",
-        ssi_multicodec::SKEIN512_384 => "SKEIN512_384",
-        ssi_multicodec::SKEIN512_392 => "SKEIN512_392",
-        ssi_multicodec::SKEIN512_400 => "SKEIN512_400",
-        ssi_multicodec::SKEIN512_408 => "SKEIN512_408",
-        ssi_multicodec::SKEIN512_416 => "SKEIN512_416",
-        ssi_multicodec::SKEIN512_424 => "SKEIN512_424",
-        ssi_multicodec::SKEIN512_432 => "SKEIN512_432",
-        ssi_multicodec::SKEIN512_440 => "SKEIN512_440",
-        ssi_multicodec::SKEIN512_448 => "SKEIN512_448",
-        ssi_multicodec::SKEIN512_456 => "SKEIN512_456",
-        ssi_multicodec::SKEIN512_464 => "SKEIN512_464",
-        ssi_multicodec::SKEIN512_472 => "SKEIN512_472",
-        ssi_multicodec::SKEIN512_480 => "SKEIN512_480",
-        ssi_multicodec::SKEIN512_488 => "SKEIN512_488",
-        ssi_multicodec::SKEIN512_496 => "SKEIN512_496",
-        ssi_multicodec::SKEIN512_504 => "SKEIN512_504",
-        ssi_multicodec::SKEIN512_512 => "SKEIN512_512",
-        ssi_multicodec::SKEIN1024_8 => "SKEIN1024_8",
-        ssi_multicodec::SKEIN1024_16 => "SKEIN1024_16",
-        ssi_multicodec::SKEIN1024_24 => "SKEIN1024_24",
-        ssi_multicodec::SKEIN1024_32 => "SKEIN1024_32",
-        ssi_multicodec::SKEIN1024_40 => "SKEIN1024_40",
-        ssi_multicodec::SKEIN1024_48 => "SKEIN1024_48",
-        ssi_multicodec::SKEIN1024_56 => "SKEIN1024_56",
-        ssi_multicodec::SKEIN1024_64 => "SKEIN1024_64",
-        ssi_multicodec::SKEIN1024_72 => "SKEIN1024_72",
-        ssi_multicodec::SKEIN1024_80 => "SKEIN1024_80",
-        ssi_multicodec::SKEIN1024_88 => "SKEIN1024_88",
-        ssi_multicodec::SKEIN1024_96 => "SKEIN1024_96",
-        ssi_multicodec::SKEIN1024_104 => "SKEIN1024_104",
-        ssi_multicodec::SKEIN1024_112 => "SKEIN1024_112",
-        ssi_multicodec::SKEIN1024_120 => "SKEIN1024_120",
-        ssi_multicodec::SKEIN1024_128 => "SKEIN1024_128",
-        ssi_multicodec::SKEIN1024_136 => "SKEIN1024_136",
-        ssi_multicodec::SKEIN1024_144 => "SKEIN1024_144",
-        ssi_multicodec::SKEIN1024_152 => "SKEIN1024_152",
-        ssi_multicodec::SKEIN1024_160 => "SKEIN1024_160",
-        ssi_multicodec::SKEIN1024_168 => "SKEIN1024_168",
-        ssi_multicodec::SKEIN1024_176 => "SKEIN1024_176",
-        ssi_multicodec::SKEIN1024_184 => "SKEIN1024_184",
-        ssi_multicodec::SKEIN1024_192 => "SKEIN1024_192",
-        ssi_multicodec::SKEIN1024_200 => "SKEIN1024_200",
-        ssi_multicodec::SKEIN1024_208 => "SKEIN1024_208",
-        ssi_multicodec::SKEIN1024_216 => "SKEIN1024_216",
-        ssi_multicodec::SKEIN1024_224 => "SKEIN1024_224",
-        ssi_multicodec::SKEIN1024_232 => "SKEIN1024_232",
-        ssi_multicodec::SKEIN1024_240 => "SKEIN1024_240",
-        ssi_multicodec::SKEIN1024_248 => "SKEIN1024_248",
-        ssi_multicodec::SKEIN1024_256 => "SKEIN1024_256",
-        ssi_multicodec::SKEIN1024_264 => "SKEIN1024_264",
-        ssi_multicodec::SKEIN1024_272 => "SKEIN1024_272",
-        ssi_multicodec::SKEIN1024_280 => "SKEIN1024_280",
-        ssi_multicodec::SKEIN1024_288 => "SKEIN1024_288",
-        ssi_multicodec::SKEIN1024_296 => "SKEIN1024_296",
-        ssi_multicodec::SKEIN1024_304 => "SKEIN1024_304",
-        ssi_multicodec::SKEIN1024_312 => "SKEIN1024_312",
-        ssi_multicodec::SKEIN1024_320 => "SKEIN1024_320",
-        ssi_multicodec::SKEIN1024_328 => "SKEIN1024_328",
-        ssi_multicodec::SKEIN1024_336 => "SKEIN1024_336",
-        ssi_multicodec::SKEIN1024_344 => "SKEIN1024_344",
-        ssi_multicodec::SKEIN1024_352 => "SKEIN1024_352",
-        ssi_multicodec::SKEIN1024_360 => "SKEIN1024_360",
-        ssi_multicodec::SKEIN1024_368 => "SKEIN1024_368",
-        ssi_multicodec::SKEIN1024_376 => "SKEIN1024_376",
-        ssi_multicodec::SKEIN1024_384 => "SKEIN1024_384",
-        ssi_multicodec::SKEIN1024_392 => "SKEIN1024_392",
-        ssi_multicodec::SKEIN1024_400 => "SKEIN1024_400",
-        ssi_multicodec::SKEIN1024_408 => "SKEIN1024_408",
-        ssi_multicodec::SKEIN1024_416 => "SKEIN1024_416",
-        ssi_multicodec::SKEIN1024_424 => "SKEIN1024_424",
-        ssi_multicodec::SKEIN1024_432 => "SKEIN1024_432",
-        ssi_multicodec::SKEIN1024_440 => "SKEIN1024_440",
-        ssi_multicodec::SKEIN1024_448 => "SKEIN1024_448",
-        ssi_multicodec::SKEIN1024_456 => "SKEIN1024_456",
-        ssi_multicodec::SKEIN1024_464 => "SKEIN1024_464",
-        ssi_multicodec::SKEIN1024_472 => "SKEIN1024_472",
-        ssi_multicodec::SKEIN1024_480 => "SKEIN1024_480",
-        ssi_multicodec::SKEIN1024_488 => "SKEIN1024_488",
-        ssi_multicodec::SKEIN1024_496 => "SKEIN1024_496",
-        ssi_multicodec::SKEIN1024_504 => "SKEIN1024_504",
-        ssi_multicodec::SKEIN1024_512 => "SKEIN1024_512",
-        ssi_multicodec::SKEIN1024_520 => "SKEIN1024_520",
-        ssi_multicodec::SKEIN1024_528 => "SKEIN1024_528",
-        ssi_multicodec::SKEIN1024_536 => "SKEIN1024_536",
-        ssi_multicodec::SKEIN1024_544 => "SKEIN1024_544",
-        ssi_multicodec::SKEIN1024_552 => "SKEIN1024_552",
-        ssi_multicodec::SKEIN1024_560 => "SKEIN1024_560",
-        ssi_multicodec::SKEIN1024_568 => "SKEIN1024_568",
-        ssi_multicodec::SKEIN1024_576 => "SKEIN1024_576",
-        ssi_multicodec::SKEIN1024_584 => "SKEIN1024_584",
-        ssi_multicodec::SKEIN1024_592 => "SKEIN1024_592",
-        ssi_multicodec::SKEIN1024_600 => "SKEIN1024_600",
-        ssi_multicodec::SKEIN1024_608 => "SKEIN1024_608",
-        ssi_multicodec::SKEIN1024_616 => "SKEIN1024_616",
-        ssi_multicodec::SKEIN1024_624 => "SKEIN1024_624",
-        ssi_multicodec::SKEIN1024_632 => "SKEIN1024_632",
-        ssi_multicodec::SKEIN1024_640 => "SKEIN1024_640",
-        ssi_multicodec::SKEIN1024_648 => "SKEIN1024_648",
-        ssi_multicodec::SKEIN1024_656 => "SKEIN1024_656",
-        ssi_multicodec::SKEIN1024_664 => "SKEIN1024_664",
-        ssi_multicodec::SKEIN1024_672 => "SKEIN1024_672",
-        ssi_multicodec::SKEIN1024_680 => "SKEIN1024_680",
-        ssi_multicodec::SKEIN1024_688 => "SKEIN1024_688",
-        ssi_multicodec::SKEIN1024_696 => "SKEIN1024_696",
-        ssi_multicodec::SKEIN1024_704 => "SKEIN1024_704",
-        ssi_multicodec::SKEIN1024_712 => "SKEIN1024_712",
-        ssi_multicodec::SKEIN1024_720 => "SKEIN1024_720",
-        ssi_multicodec::SKEIN1024_728 => "SKEIN1024_728",
-        ssi_multicodec::SKEIN1024_736 => "SKEIN1024_736",
-        ssi_multicodec::SKEIN1024_744 => "SKEIN1024_744",
-        ssi_multicodec::SKEIN1024_752 => "SKEIN1024_752",
-        ssi_multicodec::SKEIN1024_760 => "SKEIN1024_760",
-        ssi_multicodec::SKEIN1024_768 => "SKEIN1024_768",
-        ssi_multicodec::SKEIN1024_776 => "SKEIN1024_776",
-        ssi_multicodec::SKEIN1024_784 => "SKEIN1024_784",
-        ssi_multicodec::SKEIN1024_792 => "SKEIN1024_792",
-        ssi_multicodec::SKEIN1024_800 => "SKEIN1024_800",
-        ssi_multicodec::SKEIN1024_808 => "SKEIN1024_808",
-        ssi_multicodec::SKEIN1024_816 => "SKEIN1024_816",
-        ssi_multicodec::SKEIN1024_824 => "SKEIN1024_824",
-        ssi_multicodec::SKEIN1024_832 => "SKEIN1024_832",
-        ssi_multicodec::SKEIN1024_840 => "SKEIN1024_840",
-        ssi_multicodec::SKEIN1024_848 => "SKEIN1024_848",
-        ssi_multicodec::SKEIN1024_856 => "SKEIN1024_856",
-        ssi_multicodec::SKEIN1024_864 => "SKEIN1024_864",
-        ssi_multicodec::SKEIN1024_872 => "SKEIN1024_872",
-        ssi_multicodec::SKEIN1024_880 => "SKEIN1024_880",
-        ssi_multicodec::SKEIN1024_888 => "SKEIN1024_888",
-        ssi_multicodec::SKEIN1024_896 => "SKEIN1024_896",
-        ssi_multicodec::SKEIN1024_904 => "SKEIN1024_904",
-        ssi_multicodec::SKEIN1024_912 => "SKEIN1024_912",
-        ssi_multicodec::SKEIN1024_920 => "SKEIN1024_920",
-        ssi_multicodec::SKEIN1024_928 => "SKEIN1024_928",
-        ssi_multicodec::SKEIN1024_936 => "SKEIN1024_936",
-        ssi_multicodec::SKEIN1024_944 => "SKEIN1024_944",
-        ssi_multicodec::SKEIN1024_952 => "SKEIN1024_952",
-        ssi_multicodec::SKEIN1024_960 => "SKEIN1024_960",
-        ssi_multicodec::SKEIN1024_968 => "SKEIN1024_968",
-        ssi_multicodec::SKEIN1024_976 => "SKEIN1024_976",
-        ssi_multicodec::SKEIN1024_984 => "SKEIN1024_984",
-        ssi_multicodec::SKEIN1024_992 => "SKEIN1024_992",
-        ssi_multicodec::SKEIN1024_1000 => "SKEIN1024_1000",
-        ssi_multicodec::SKEIN1024_1008 => "SKEIN1024_1008",
-        ssi_multicodec::SKEIN1024_1016 => "SKEIN1024_1016",
-        ssi_multicodec::SKEIN1024_1024 => "SKEIN1024_1024",
+    codec_info(codec)
+        .map(|codec_info| codec_info.const_name)
+        .ok_or_else(|| {
+            error!(
+                kind: ErrorKind::UnsupportedCodec { codec },
+                "Unknown codec: 0x{:02x}",
+                codec
+            )
+        })
+}
 
-        // PrivKey category
-        ssi_multicodec::ED25519_PRIV => "ED25519_PRIV",
-        ssi_multicodec::SECP256K1_PRIV => "SECP256K1_PRIV",
-        ssi_multicodec::X25519_PRIV => "X25519_PRIV",
-        ssi_multicodec::SR25519_PRIV => "SR25519_PRIV",
-        ssi_multicodec::RSA_PRIV => "RSA_PRIV",
-        ssi_multicodec::P256_PRIV => "P256_PRIV",
-        ssi_multicodec::P384_PRIV => "P384_PRIV",
-        ssi_multicodec::P521_PRIV => "P521_PRIV",
+/// Returns the codec having the given name, which may be either its name in the multicodec table
+/// (e.g. `"sha2-256"`) or the name of the `ssi_multicodec` constant (e.g. `"SHA2_256"`), compared
+/// case-insensitively.  This is the inverse of `codec_str`.
+pub fn codec_from_str(s: &str) -> Result<u64> {
+    codec_info_table()
+        .iter()
+        .find(|codec_info| {
+            codec_info.name.eq_ignore_ascii_case(s) || codec_info.const_name.eq_ignore_ascii_case(s)
+        })
+        .map(|codec_info| codec_info.code)
+        .ok_or_else(|| error!(kind: ErrorKind::UnknownCodecName, "Unknown codec name: {:?}", s))
+}
 
-        // PubKey category
-        ssi_multicodec::SECP256K1_PUB => "SECP256K1_PUB",
-        ssi_multicodec::BLS12_381_G1_PUB => "BLS12_381_G1_PUB",
-        ssi_multicodec::BLS12_381_G2_PUB => "BLS12_381_G2_PUB",
-        ssi_multicodec::X25519_PUB => "X25519_PUB",
-        ssi_multicodec::ED25519_PUB => "ED25519_PUB",
-        ssi_multicodec::BLS12_381_G1G2_PUB => "BLS12_381_G1G2_PUB",
-        ssi_multicodec::SR25519_PUB => "SR25519_PUB",
-        ssi_multicodec::P256_PUB => "P256_PUB",
-        ssi_multicodec::P384_PUB => "P384_PUB",
-        ssi_multicodec::P521_PUB => "P521_PUB",
-        ssi_multicodec::ED448_PUB => "ED448_PUB",
-        ssi_multicodec::X448_PUB => "X448_PUB",
-        ssi_multicodec::RSA_PUB => "RSA_PUB",
-        ssi_multicodec::SM2_PUB => "SM2_PUB",
-        ssi_multicodec::JWK_JCS_PUB => "JWK_JCS_PUB",
+#[cfg(test)]
+mod tests {
+    use crate::{CodecCategory, ErrorKind, codec_from_str, codec_info_table, codec_str};
 
-        // SymmetricKey category
-        ssi_multicodec::AES_128 => "AES_128",
-        ssi_multicodec::AES_192 => "AES_192",
-        ssi_multicodec::AES_256 => "AES_256",
-        ssi_multicodec::CHACHA_128 => "CHACHA_128",
-        ssi_multicodec::CHACHA_256 => "CHACHA_256",
+    #[test]
+    fn test_codec_from_str() {
+        assert_eq!(
+            codec_from_str("sha2-256").expect("pass"),
+            ssi_multicodec::SHA2_256
+        );
+        assert_eq!(
+            codec_from_str("SHA2_256").expect("pass"),
+            ssi_multicodec::SHA2_256
+        );
+        assert_eq!(
+            codec_from_str("ED25519_PUB").expect("pass"),
+            ssi_multicodec::ED25519_PUB
+        );
+        assert_eq!(
+            codec_from_str("Ed25519-Pub").expect("pass"),
+            ssi_multicodec::ED25519_PUB
+        );
+        assert_eq!(
+            codec_from_str("ed448-priv").expect("pass"),
+            crate::ED448_PRIV_CODEC
+        );
+        for s in ["HIPPO-256", ""] {
+            let err = codec_from_str(s).expect_err("pass");
+            assert_eq!(err.kind(), &ErrorKind::UnknownCodecName);
+        }
 
-        // Signature category
-        ssi_multicodec::ES256K => "ES256K",
-        ssi_multicodec::BLS_12381_G1_SIG => "BLS_12381_G1_SIG",
-        ssi_multicodec::BLS_12381_G2_SIG => "BLS_12381_G2_SIG",
-        ssi_multicodec::EDDSA => "EDDSA",
-        ssi_multicodec::EIP_191 => "EIP_191",
-        ssi_multicodec::ES256 => "ES256",
-        // NOTE: This is a typo in the multicodec table.  It should be ES384.
-        ssi_multicodec::ES284 => "ES284",
-        ssi_multicodec::ES512 => "ES512",
-        ssi_multicodec::RS256 => "RS256",
+        // codec_from_str is the inverse of codec_str.
+        for codec_info in codec_info_table() {
+            let s = codec_str(codec_info.code).expect("pass");
+            assert_eq!(codec_from_str(s).expect("pass"), codec_info.code);
+            assert_eq!(
+                codec_from_str(codec_info.name).expect("pass"),
+                codec_info.code
+            );
+        }
+    }
 
-        _ => {
-            bail!("Unknown codec: 0x{:02x}", codec);
+    #[test]
+    fn test_codec_str_is_total() {
+        // Every codec that CodecCategory::from_codec classifies has a codec_str.
+        for codec in 0..=0xffffu64 {
+            if CodecCategory::from_codec(codec) != CodecCategory::Unspecified {
+                assert!(codec_str(codec).is_ok(), "codec: 0x{:02x}", codec);
+            }
+        }
+        for codec_info in codec_info_table() {
+            assert_eq!(
+                codec_str(codec_info.code).expect("pass"),
+                codec_info.const_name
+            );
+        }
+        for (codec, expected_codec_str) in [
+            (ssi_multicodec::BLAKE2B_8, "BLAKE2B_8"),
+            (ssi_multicodec::BLAKE2B_88, "BLAKE2B_88"),
+            (ssi_multicodec::BLAKE2S_256, "BLAKE2S_256"),
+            (ssi_multicodec::SKEIN256_8, "SKEIN256_8"),
+            (ssi_multicodec::SKEIN1024_1024, "SKEIN1024_1024"),
+            (ssi_multicodec::SM3_256, "SM3_256"),
+            (ssi_multicodec::KANGAROOTWELVE, "KANGAROOTWELVE"),
+            (ssi_multicodec::X11, "X11"),
+            (ssi_multicodec::AES_256, "AES_256"),
+            (crate::ED448_PRIV_CODEC, "ED448_PRIV"),
+        ] {
+            assert_eq!(codec_str(codec).expect("pass"), expected_codec_str);
         }
-    };
-    Ok(s)
+        assert!(codec_str(0x1234567).is_err());
+    }
 }
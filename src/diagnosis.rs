@@ -0,0 +1,300 @@
+use crate::{Base, CodecCategorizableT, CodecCategory, Error, codec_info, mbx_str_validate_impl};
+
+/// The maximum number of bytes in a varint-encoded codec (see <https://github.com/multiformats/unsigned-varint>).
+const MAX_VARINT_LEN: usize = 9;
+
+/// A detailed account of what was found while parsing a (possibly malformed) multibase string as an
+/// `MBXStr`, e.g. for use in linters and error messages that point at the offending part of the input.
+/// Each field is filled in as far as parsing got, so e.g. `codec_o` is `None` if the multibase
+/// decoding failed.  See `MBXStr::diagnose`.
+#[derive(Clone, Debug)]
+pub struct Diagnosis {
+    /// The codec category that the string was checked against.
+    pub expected_codec_category: CodecCategory,
+    /// The first character of the string, which indicates the base, or `None` if the string is empty.
+    pub base_char_o: Option<char>,
+    /// The base indicated by the first character, or `None` if it's not a known base.
+    pub base_o: Option<Base>,
+    /// The byte offset and value of the first character that isn't in the alphabet of the base.
+    pub invalid_char_o: Option<(usize, char)>,
+    /// True if the decoded bytes ended in the middle of the varint-encoded codec.
+    pub varint_truncated: bool,
+    /// The decoded codec.
+    pub codec_o: Option<u64>,
+    /// The category of the decoded codec.
+    pub codec_category_o: Option<CodecCategory>,
+    /// The number of bytes following the varint-encoded codec.
+    pub payload_len_o: Option<usize>,
+    /// The number of bytes that the decoded codec requires, if it's known.
+    pub expected_payload_len_o: Option<usize>,
+    /// The error that validation produces for this string, or `None` if it is valid.
+    pub error_o: Option<Error>,
+}
+
+impl Diagnosis {
+    /// Returns true if the string is a valid `MBXStr` for the expected codec category.
+    pub fn is_valid(&self) -> bool {
+        self.error_o.is_none()
+    }
+}
+
+impl std::fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.base_char_o, self.base_o) {
+            (None, _) => write!(f, "empty string")?,
+            (Some(base_char), Some(base)) => write!(f, "base {:?} (prefix {:?})", base, base_char)?,
+            (Some(base_char), None) => write!(f, "unknown base prefix {:?}", base_char)?,
+        }
+        if let Some((offset, invalid_char)) = self.invalid_char_o {
+            write!(
+                f,
+                "; invalid character {:?} at byte offset {}",
+                invalid_char, offset
+            )?;
+        }
+        if self.varint_truncated {
+            write!(f, "; truncated varint codec prefix")?;
+        }
+        if let (Some(codec), Some(codec_category)) = (self.codec_o, self.codec_category_o) {
+            write!(
+                f,
+                "; codec 0x{:02x} in category {:?}",
+                codec, codec_category
+            )?;
+        }
+        if let Some(payload_len) = self.payload_len_o {
+            write!(f, "; {} payload bytes", payload_len)?;
+            if let Some(expected_payload_len) = self.expected_payload_len_o {
+                write!(f, " (expected {})", expected_payload_len)?;
+            }
+        }
+        match &self.error_o {
+            Some(error) => write!(f, "; invalid: {}", error),
+            None => write!(f, "; valid"),
+        }
+    }
+}
+
+/// Parses the string step by step, recording what was found at each step.  This never fails; the
+/// validation error (if any) is recorded in `Diagnosis::error_o`.
+pub(crate) fn diagnose_impl<C: CodecCategorizableT>(data: &str) -> Diagnosis {
+    let mut diagnosis = Diagnosis {
+        expected_codec_category: C::codec_category(),
+        base_char_o: data.chars().next(),
+        base_o: None,
+        invalid_char_o: None,
+        varint_truncated: false,
+        codec_o: None,
+        codec_category_o: None,
+        payload_len_o: None,
+        expected_payload_len_o: None,
+        error_o: mbx_str_validate_impl::<C>(data).err(),
+    };
+    let Some(base_char) = diagnosis.base_char_o else {
+        return diagnosis;
+    };
+    diagnosis.base_o = Base::from_code(base_char).ok();
+
+    let decoded_byte_v = match multibase::decode(data) {
+        Ok((_base, decoded_byte_v)) => decoded_byte_v,
+        Err(_) => {
+            // Only look for an invalid character if decoding failed, so that one is never reported
+            // for a string that decodes.
+            let encoded = &data[base_char.len_utf8()..];
+            if let Some(alphabet) = alphabet_for_base_char(base_char) {
+                let case_insensitive = is_case_insensitive_base_char(base_char);
+                diagnosis.invalid_char_o = encoded
+                    .char_indices()
+                    .find(|(_, c)| {
+                        if case_insensitive {
+                            !alphabet.contains(c.to_ascii_lowercase())
+                                && !alphabet.contains(c.to_ascii_uppercase())
+                        } else {
+                            !alphabet.contains(*c)
+                        }
+                    })
+                    .map(|(offset, c)| (base_char.len_utf8() + offset, c));
+            }
+            return diagnosis;
+        }
+    };
+    match decode_varint(&decoded_byte_v) {
+        VarintDecoded::Complete { value, len } => {
+            diagnosis.codec_o = Some(value);
+            diagnosis.codec_category_o = Some(CodecCategory::from_codec(value));
+            diagnosis.payload_len_o = Some(decoded_byte_v.len() - len);
+            diagnosis.expected_payload_len_o =
+                codec_info(value).and_then(|codec_info| codec_info.expected_len_o);
+        }
+        VarintDecoded::Truncated => {
+            diagnosis.varint_truncated = true;
+        }
+        VarintDecoded::Overflow => {}
+    }
+    diagnosis
+}
+
+/// Returns true if the multibase crate decodes the base case-insensitively (base16, base32, and base36,
+/// but not base32z), in which case the case of the prefix character only indicates the case of the
+/// canonical encoding.
+fn is_case_insensitive_base_char(base_char: char) -> bool {
+    matches!(
+        base_char,
+        'f' | 'F' | 'b' | 'B' | 'c' | 'C' | 'v' | 'V' | 't' | 'T' | 'k' | 'K'
+    )
+}
+
+/// Returns the characters allowed after the base prefix character, or `None` if the base is unknown
+/// or its alphabet isn't checked here (identity and base256emoji).
+fn alphabet_for_base_char(base_char: char) -> Option<&'static str> {
+    let alphabet = match base_char {
+        '0' => "01",
+        '7' => "01234567",
+        '9' => "0123456789",
+        'f' => "0123456789abcdef",
+        'F' => "0123456789ABCDEF",
+        'b' => "abcdefghijklmnopqrstuvwxyz234567",
+        'B' => "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+        'c' => "abcdefghijklmnopqrstuvwxyz234567=",
+        'C' => "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567=",
+        'v' => "0123456789abcdefghijklmnopqrstuv",
+        'V' => "0123456789ABCDEFGHIJKLMNOPQRSTUV",
+        't' => "0123456789abcdefghijklmnopqrstuv=",
+        'T' => "0123456789ABCDEFGHIJKLMNOPQRSTUV=",
+        'h' => "ybndrfg8ejkmcpqxot1uwisza345h769",
+        'k' => "0123456789abcdefghijklmnopqrstuvwxyz",
+        'K' => "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+        'Z' => "123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ",
+        'z' => "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+        'm' => "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        'M' => "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/=",
+        'u' => "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+        'U' => "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_=",
+        _ => return None,
+    };
+    Some(alphabet)
+}
+
+enum VarintDecoded {
+    Complete { value: u64, len: usize },
+    Truncated,
+    Overflow,
+}
+
+/// Decodes an unsigned varint from the start of the given bytes, distinguishing truncation (the input
+/// ends while the continuation bit is set) from overflow (the varint is too long).
+fn decode_varint(byte_v: &[u8]) -> VarintDecoded {
+    let mut value = 0u64;
+    for (i, &byte) in byte_v.iter().enumerate().take(MAX_VARINT_LEN) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return VarintDecoded::Complete { value, len: i + 1 };
+        }
+    }
+    if byte_v.len() < MAX_VARINT_LEN {
+        VarintDecoded::Truncated
+    } else {
+        VarintDecoded::Overflow
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Base, CodecCategory, ErrorKind, MBPubKeyStr};
+
+    fn encode(codec: u64, byte_v: &[u8]) -> String {
+        let multi_encoded = ssi_multicodec::MultiEncodedBuf::encode_bytes(codec, byte_v);
+        multibase::encode(Base::Base58Btc, multi_encoded.as_bytes())
+    }
+
+    #[test]
+    fn test_diagnose_valid() {
+        let diagnosis = MBPubKeyStr::diagnose("z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp");
+        println!("diagnosis: {}", diagnosis);
+        assert!(diagnosis.is_valid());
+        assert_eq!(diagnosis.base_char_o, Some('z'));
+        assert_eq!(diagnosis.base_o, Some(Base::Base58Btc));
+        assert_eq!(diagnosis.invalid_char_o, None);
+        assert!(!diagnosis.varint_truncated);
+        assert_eq!(diagnosis.codec_o, Some(ssi_multicodec::ED25519_PUB));
+        assert_eq!(diagnosis.codec_category_o, Some(CodecCategory::PubKey));
+        assert_eq!(diagnosis.payload_len_o, Some(32));
+        assert_eq!(diagnosis.expected_payload_len_o, Some(32));
+    }
+
+    #[test]
+    fn test_diagnose_valid_mixed_case() {
+        // base16, base32, and base36 are decoded case-insensitively, so mixed-case data is valid and
+        // has no invalid character.
+        for base in [Base::Base16Lower, Base::Base32Lower, Base::Base36Lower] {
+            let multi_encoded = ssi_multicodec::MultiEncodedBuf::encode_bytes(
+                ssi_multicodec::ED25519_PUB,
+                &[7u8; 32],
+            );
+            let lowercase = multibase::encode(base, multi_encoded.as_bytes());
+            let mixed_case = lowercase
+                .char_indices()
+                .map(|(i, c)| {
+                    if i % 2 == 0 {
+                        c
+                    } else {
+                        c.to_ascii_uppercase()
+                    }
+                })
+                .collect::<String>();
+            assert!(mixed_case.starts_with(lowercase.chars().next().expect("pass")));
+            let diagnosis = MBPubKeyStr::diagnose(&mixed_case);
+            println!("diagnosis: {}", diagnosis);
+            assert!(diagnosis.is_valid());
+            assert_eq!(diagnosis.invalid_char_o, None);
+            assert_eq!(diagnosis.codec_o, Some(ssi_multicodec::ED25519_PUB));
+        }
+    }
+
+    #[test]
+    fn test_diagnose_invalid() {
+        let diagnosis = MBPubKeyStr::diagnose("");
+        println!("diagnosis: {}", diagnosis);
+        assert!(!diagnosis.is_valid());
+        assert_eq!(diagnosis.base_char_o, None);
+
+        let diagnosis = MBPubKeyStr::diagnose("!abc");
+        println!("diagnosis: {}", diagnosis);
+        assert_eq!(diagnosis.base_char_o, Some('!'));
+        assert_eq!(diagnosis.base_o, None);
+        assert_eq!(
+            diagnosis.error_o.as_ref().expect("pass").kind(),
+            &ErrorKind::InvalidBase
+        );
+
+        // '0' and 'l' are not in the base58btc alphabet.
+        let diagnosis = MBPubKeyStr::diagnose("z6Mki0Bz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWl");
+        println!("diagnosis: {}", diagnosis);
+        assert_eq!(diagnosis.invalid_char_o, Some((5, '0')));
+        assert_eq!(diagnosis.codec_o, None);
+
+        let diagnosis = MBPubKeyStr::diagnose(&multibase::encode(Base::Base58Btc, [0xed]));
+        println!("diagnosis: {}", diagnosis);
+        assert!(diagnosis.varint_truncated);
+        assert_eq!(diagnosis.codec_o, None);
+        assert!(!diagnosis.is_valid());
+
+        let diagnosis = MBPubKeyStr::diagnose(&encode(ssi_multicodec::ED25519_PRIV, &[0u8; 32]));
+        println!("diagnosis: {}", diagnosis);
+        assert_eq!(diagnosis.codec_category_o, Some(CodecCategory::PrivKey));
+        assert!(!diagnosis.is_valid());
+
+        let diagnosis = MBPubKeyStr::diagnose(&encode(ssi_multicodec::ED25519_PUB, &[0u8; 31]));
+        println!("diagnosis: {}", diagnosis);
+        assert_eq!(diagnosis.payload_len_o, Some(31));
+        assert_eq!(diagnosis.expected_payload_len_o, Some(32));
+        assert_eq!(
+            diagnosis.error_o.as_ref().expect("pass").kind(),
+            &ErrorKind::WrongByteLength {
+                codec: ssi_multicodec::ED25519_PUB,
+                expected: 32,
+                actual: 31,
+            }
+        );
+    }
+}
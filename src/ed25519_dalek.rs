@@ -1,4 +1,7 @@
-use crate::{Error, MBPrivKey, MBPrivKeyStr, MBPubKey, MBPubKeyStr, ensure};
+use crate::{
+    Ed25519, Error, ErrorKind, MBPrivKey, MBPrivKeyOf, MBPrivKeyStr, MBPubKey, MBPubKeyOf,
+    MBPubKeyStr, ensure,
+};
 
 //
 // SigningKey
@@ -10,6 +13,10 @@ impl TryFrom<&MBPrivKeyStr> for ed25519_dalek::SigningKey {
         let decoded = mb_priv_key.decoded()?;
         ensure!(
             decoded.codec() == ssi_multicodec::ED25519_PRIV,
+            kind: ErrorKind::WrongCodec {
+                expected: ssi_multicodec::ED25519_PRIV,
+                actual: decoded.codec(),
+            },
             "Expected codec ED25519_PRIV 0x({:02x}), got 0x{:02x}",
             ssi_multicodec::ED25519_PRIV,
             decoded.codec()
@@ -44,6 +51,10 @@ impl TryFrom<&MBPubKeyStr> for ed25519_dalek::VerifyingKey {
         let decoded = mb_pub_key.decoded()?;
         ensure!(
             decoded.codec() == ssi_multicodec::ED25519_PUB,
+            kind: ErrorKind::WrongCodec {
+                expected: ssi_multicodec::ED25519_PUB,
+                actual: decoded.codec(),
+            },
             "Expected codec ED25519_PUB 0x({:02x}), got 0x{:02x}",
             ssi_multicodec::ED25519_PUB,
             decoded.codec()
@@ -68,9 +79,39 @@ impl TryFrom<MBPubKey> for ed25519_dalek::VerifyingKey {
     }
 }
 
+//
+// MBPrivKeyOf and MBPubKeyOf
+//
+
+// These are infallible by the validation invariant of `KeyCodecT`.
+
+impl From<&MBPrivKeyOf<Ed25519>> for ed25519_dalek::SigningKey {
+    fn from(mb_priv_key: &MBPrivKeyOf<Ed25519>) -> Self {
+        Self::try_from(mb_priv_key.as_mb_priv_key_str()).expect("programmer error")
+    }
+}
+
+impl From<MBPrivKeyOf<Ed25519>> for ed25519_dalek::SigningKey {
+    fn from(mb_priv_key: MBPrivKeyOf<Ed25519>) -> Self {
+        Self::from(&mb_priv_key)
+    }
+}
+
+impl From<&MBPubKeyOf<Ed25519>> for ed25519_dalek::VerifyingKey {
+    fn from(mb_pub_key: &MBPubKeyOf<Ed25519>) -> Self {
+        Self::try_from(mb_pub_key.as_mb_pub_key_str()).expect("programmer error")
+    }
+}
+
+impl From<MBPubKeyOf<Ed25519>> for ed25519_dalek::VerifyingKey {
+    fn from(mb_pub_key: MBPubKeyOf<Ed25519>) -> Self {
+        Self::from(&mb_pub_key)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Base, MBPrivKey, MBPubKey};
+    use crate::{Base, Ed25519, MBPrivKey, MBPrivKeyOf, MBPubKey, MBPubKeyOf};
 
     fn test_ed25519_dalek_encode_decode_case(base: Base) {
         let mut rng = rand::rand_core::UnwrapErr(rand::rngs::SysRng);
@@ -89,6 +130,17 @@ mod tests {
 
         assert_eq!(signing_key_decoded, signing_key);
         assert_eq!(verifying_key_decoded, verifying_key);
+
+        let mb_priv_key_of = MBPrivKeyOf::<Ed25519>::try_from(mb_priv_key).expect("pass");
+        let mb_pub_key_of = MBPubKeyOf::<Ed25519>::try_from(mb_pub_key).expect("pass");
+        assert_eq!(
+            ed25519_dalek::SigningKey::from(&mb_priv_key_of),
+            signing_key
+        );
+        assert_eq!(
+            ed25519_dalek::VerifyingKey::from(mb_pub_key_of),
+            verifying_key
+        );
     }
 
     #[test]
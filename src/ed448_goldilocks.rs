@@ -1,5 +1,6 @@
 use crate::{
-    ED448_PRIV_CODEC, Error, MBPrivKey, MBPrivKeyStr, MBPubKey, MBPubKeyStr, ensure, error,
+    ED448_PRIV_CODEC, Ed448, Error, ErrorKind, MBPrivKey, MBPrivKeyOf, MBPrivKeyStr, MBPubKey,
+    MBPubKeyOf, MBPubKeyStr, ensure, error,
 };
 
 //
@@ -15,6 +16,10 @@ impl TryFrom<&MBPrivKeyStr> for ed448_goldilocks::SigningKey {
         let decoded = mb_priv_key.decoded()?;
         ensure!(
             decoded.codec() == ED448_PRIV_CODEC,
+            kind: ErrorKind::WrongCodec {
+                expected: ED448_PRIV_CODEC,
+                actual: decoded.codec(),
+            },
             "Expected codec ED448_PRIV 0x({:02x}), got 0x{:02x}",
             ED448_PRIV_CODEC,
             decoded.codec()
@@ -49,6 +54,10 @@ impl TryFrom<&MBPubKeyStr> for ed448_goldilocks::VerifyingKey {
         let decoded = mb_pub_key.decoded()?;
         ensure!(
             decoded.codec() == ssi_multicodec::ED448_PUB,
+            kind: ErrorKind::WrongCodec {
+                expected: ssi_multicodec::ED448_PUB,
+                actual: decoded.codec(),
+            },
             "Expected codec ED448_PUB 0x({:02x}), got 0x{:02x}",
             ssi_multicodec::ED448_PUB,
             decoded.codec()
@@ -56,6 +65,11 @@ impl TryFrom<&MBPubKeyStr> for ed448_goldilocks::VerifyingKey {
         let bytes = decoded.data();
         let byte_array = <&[u8; 57]>::try_from(bytes).map_err(|_| {
             error!(
+                kind: ErrorKind::WrongByteLength {
+                    codec: ssi_multicodec::ED448_PUB,
+                    expected: 57,
+                    actual: bytes.len(),
+                },
                 "Invalid ED448 public key; incorrect length -- got {} bytes, expected 57",
                 bytes.len(),
             )
@@ -79,6 +93,36 @@ impl TryFrom<MBPubKey> for ed448_goldilocks::VerifyingKey {
     }
 }
 
+//
+// MBPrivKeyOf and MBPubKeyOf
+//
+
+// These are infallible by the validation invariant of `KeyCodecT`.
+
+impl From<&MBPrivKeyOf<Ed448>> for ed448_goldilocks::SigningKey {
+    fn from(mb_priv_key: &MBPrivKeyOf<Ed448>) -> Self {
+        Self::try_from(mb_priv_key.as_mb_priv_key_str()).expect("programmer error")
+    }
+}
+
+impl From<MBPrivKeyOf<Ed448>> for ed448_goldilocks::SigningKey {
+    fn from(mb_priv_key: MBPrivKeyOf<Ed448>) -> Self {
+        Self::from(&mb_priv_key)
+    }
+}
+
+impl From<&MBPubKeyOf<Ed448>> for ed448_goldilocks::VerifyingKey {
+    fn from(mb_pub_key: &MBPubKeyOf<Ed448>) -> Self {
+        Self::try_from(mb_pub_key.as_mb_pub_key_str()).expect("programmer error")
+    }
+}
+
+impl From<MBPubKeyOf<Ed448>> for ed448_goldilocks::VerifyingKey {
+    fn from(mb_pub_key: MBPubKeyOf<Ed448>) -> Self {
+        Self::from(&mb_pub_key)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Base, MBPubKey};
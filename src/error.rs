@@ -1,34 +1,120 @@
-use std::borrow::Cow;
+use crate::CodecCategory;
+use std::{borrow::Cow, sync::Arc};
+
+/// Classifies an `Error`, so that callers can match on the kind of failure instead of on its message.
+/// New kinds may be added in the future, hence `#[non_exhaustive]`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The multibase encoding was invalid, e.g. an unknown base prefix or a character not in the base's alphabet.
+    InvalidBase,
+    /// The varint-encoded codec was invalid or truncated.
+    InvalidVarint,
+    /// The multihash encoding was invalid, e.g. the digest length didn't match the declared size.
+    InvalidMultihash,
+    /// The codec was valid but in the wrong category, e.g. a private key codec where a public key codec was expected.
+    WrongCodecCategory {
+        expected: CodecCategory,
+        actual: CodecCategory,
+    },
+    /// The codec was in the right category but wasn't the one that was required.
+    WrongCodec { expected: u64, actual: u64 },
+    /// The byte length of the decoded value didn't match what its codec requires.
+    WrongByteLength {
+        codec: u64,
+        expected: usize,
+        actual: usize,
+    },
+    /// The codec is not known or not supported by the requested operation.
+    UnsupportedCodec { codec: u64 },
+    /// No codec has the given name.
+    UnknownCodecName,
+    /// The operation requires a crate feature that is not enabled.
+    FeatureDisabled { feature: &'static str },
+    /// The key bytes were rejected by the underlying cryptographic library.
+    InvalidKeyMaterial,
+    /// A hash, signature, or proof did not verify.
+    VerificationFailed,
+    /// An I/O error occurred.
+    Io(std::io::ErrorKind),
+    /// Any other error.
+    Other,
+}
 
 #[derive(Clone, Debug)]
-pub struct Error(Cow<'static, str>);
+pub struct Error {
+    kind: ErrorKind,
+    message: Cow<'static, str>,
+    /// The underlying error, if this error wraps one (e.g. from a dependency).  This is an `Arc` so
+    /// that `Error` can remain `Clone`.
+    source_o: Option<Arc<dyn std::error::Error + Send + Sync + 'static>>,
+}
 
 impl Error {
+    pub fn new(kind: ErrorKind, message: Cow<'static, str>) -> Self {
+        Self {
+            kind,
+            message,
+            source_o: None,
+        }
+    }
+    /// Creates an error of kind `ErrorKind::Other`.
     pub fn from_cow(s: Cow<'static, str>) -> Self {
-        Error(s)
+        Self::new(ErrorKind::Other, s)
     }
     pub fn into_cow(self) -> Cow<'static, str> {
-        self.0
+        self.message
+    }
+    /// Returns the kind of this error.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+    /// Returns the message of this error, which is also what `Display` prints.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+    /// Replaces the kind of this error, e.g. when the caller knows more about the context of the
+    /// failure than the `From` impl that produced it.
+    pub fn with_kind(mut self, kind: ErrorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+    /// Attaches the underlying error, which is then returned by `std::error::Error::source`.
+    pub fn with_source(mut self, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        self.source_o = Some(Arc::new(source));
+        self
     }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.message)
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source_o
+            .as_deref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorKind::Io(e.kind()), e.to_string().into()).with_source(e)
+    }
+}
 
 impl From<multibase::Error> for Error {
     fn from(e: multibase::Error) -> Self {
-        Self::from_cow(e.to_string().into())
+        Self::new(ErrorKind::InvalidBase, e.to_string().into()).with_source(e)
     }
 }
 
 impl From<multihash::Error> for Error {
     fn from(e: multihash::Error) -> Self {
-        Self::from_cow(e.to_string().into())
+        Self::new(ErrorKind::InvalidMultihash, e.to_string().into()).with_source(e)
     }
 }
 
@@ -43,20 +129,21 @@ impl From<multihash::Error> for Error {
 ))]
 impl From<signature::Error> for Error {
     fn from(e: signature::Error) -> Self {
-        Self::from_cow(e.to_string().into())
+        Self::new(ErrorKind::InvalidKeyMaterial, e.to_string().into()).with_source(e)
     }
 }
 
 #[cfg(feature = "signature-dyn")]
 impl From<signature_dyn::Error> for Error {
     fn from(e: signature_dyn::Error) -> Self {
-        Self::from_cow(e.into_cow())
+        Self::new(ErrorKind::InvalidKeyMaterial, e.to_string().into()).with_source(e)
     }
 }
 
 impl From<ssi_multicodec::Error> for Error {
     fn from(e: ssi_multicodec::Error) -> Self {
-        Self::from_cow(e.to_string().into())
+        // MultiEncodedBuf::new only fails if the varint-encoded codec is invalid.
+        Self::new(ErrorKind::InvalidVarint, e.to_string().into()).with_source(e)
     }
 }
 
@@ -72,8 +159,16 @@ impl From<&str> for Error {
     }
 }
 
+/// Creates an `Error` from a format string, optionally preceded by `kind: <ErrorKind>,` (the default
+/// kind is `ErrorKind::Other`).
 #[macro_export]
 macro_rules! error {
+    (kind: $kind:expr, $fmt:literal) => {
+        $crate::Error::new($kind, std::borrow::Cow::Borrowed($fmt))
+    };
+    (kind: $kind:expr, $fmt:literal, $($arg:tt)*) => {
+        $crate::Error::new($kind, std::borrow::Cow::Owned(format!($fmt, $($arg)*)))
+    };
     ($fmt:literal) => {
         $crate::Error::from_cow(std::borrow::Cow::Borrowed($fmt))
     };
@@ -84,6 +179,12 @@ macro_rules! error {
 
 #[macro_export]
 macro_rules! bail {
+    (kind: $kind:expr, $fmt:literal) => {{
+        return Err($crate::error!(kind: $kind, $fmt));
+    }};
+    (kind: $kind:expr, $fmt:literal, $($arg:tt)*) => {{
+        return Err($crate::error!(kind: $kind, $fmt, $($arg)*));
+    }};
     ($fmt:literal) => {{
         return Err($crate::error!($fmt));
     }};
@@ -94,6 +195,16 @@ macro_rules! bail {
 
 #[macro_export]
 macro_rules! ensure {
+    ($condition: expr, kind: $kind:expr, $fmt:literal) => {
+        if !$condition {
+            $crate::bail!(kind: $kind, $fmt);
+        }
+    };
+    ($condition: expr, kind: $kind:expr, $fmt:literal, $($arg:tt)*) => {
+        if !$condition {
+            $crate::bail!(kind: $kind, $fmt, $($arg)*);
+        }
+    };
     ($condition: expr, $fmt:literal) => {
         if !$condition {
             $crate::bail!($fmt);
@@ -105,3 +216,32 @@ macro_rules! ensure {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ErrorKind, MBPubKeyStr};
+
+    #[test]
+    fn test_error_source() {
+        use std::error::Error as _;
+
+        let err = MBPubKeyStr::new_ref("!abc").expect_err("pass");
+        println!("err: {}", err);
+        assert_eq!(err.kind(), &ErrorKind::InvalidBase);
+        let source = err.source().expect("pass");
+        assert!(source.downcast_ref::<multibase::Error>().is_some());
+        // The source survives cloning.
+        let cloned_err = err.clone();
+        assert!(
+            cloned_err
+                .source()
+                .expect("pass")
+                .downcast_ref::<multibase::Error>()
+                .is_some()
+        );
+
+        // Errors created via the macros have no source.
+        let err = crate::error!("HIPPO");
+        assert!(err.source().is_none());
+    }
+}
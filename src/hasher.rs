@@ -0,0 +1,367 @@
+use crate::{Base, ErrorKind, MBHash, Result, bail};
+
+/// The buffer size used when feeding a reader into a hasher.  This is large so that hashing large
+/// files doesn't incur a lot of per-read overhead.
+const READ_BUFFER_SIZE: usize = 1 << 20;
+
+/// A hasher for one of the multihash codecs supported by the enabled features of this crate
+/// (`blake2`, `blake3`, `sha2`, `sha3`), chosen at runtime by codec.  With the `blake2` feature, every
+/// BLAKE2b and BLAKE2s output length is supported, not just the common ones.  This is useful when the hash
+/// function is determined by an existing `MBHash` value or by configuration.
+#[allow(non_camel_case_types)]
+#[derive(Clone)]
+pub enum Hasher {
+    #[cfg(feature = "blake2")]
+    Blake2b_256(blake2::Blake2b256),
+    #[cfg(feature = "blake2")]
+    Blake2b_512(blake2::Blake2b512),
+    #[cfg(feature = "blake2")]
+    Blake2s_256(blake2::Blake2s256),
+    /// BLAKE2b with any of the other output lengths (BLAKE2B_8 through BLAKE2B_512), chosen at runtime.
+    #[cfg(feature = "blake2")]
+    Blake2bVar(blake2::Blake2bVar),
+    /// BLAKE2s with any of the other output lengths (BLAKE2S_8 through BLAKE2S_256), chosen at runtime.
+    #[cfg(feature = "blake2")]
+    Blake2sVar(blake2::Blake2sVar),
+    #[cfg(feature = "blake3")]
+    Blake3(blake3::Hasher),
+    #[cfg(feature = "sha2")]
+    Sha_224(sha2::Sha224),
+    #[cfg(feature = "sha2")]
+    Sha_256(sha2::Sha256),
+    #[cfg(feature = "sha2")]
+    Sha_384(sha2::Sha384),
+    #[cfg(feature = "sha2")]
+    Sha_512(sha2::Sha512),
+    #[cfg(feature = "sha3")]
+    Sha3_224(sha3::Sha3_224),
+    #[cfg(feature = "sha3")]
+    Sha3_256(sha3::Sha3_256),
+    #[cfg(feature = "sha3")]
+    Sha3_384(sha3::Sha3_384),
+    #[cfg(feature = "sha3")]
+    Sha3_512(sha3::Sha3_512),
+}
+
+impl Hasher {
+    /// Creates a new hasher for the given multihash codec.  Returns an error if the codec is not a
+    /// supported hash function, or if the feature that provides it is not enabled.
+    pub fn new(codec: u64) -> Result<Self> {
+        let hasher = match codec {
+            #[cfg(feature = "blake2")]
+            ssi_multicodec::BLAKE2B_256 => Self::Blake2b_256(Default::default()),
+            #[cfg(feature = "blake2")]
+            ssi_multicodec::BLAKE2B_512 => Self::Blake2b_512(Default::default()),
+            #[cfg(feature = "blake2")]
+            ssi_multicodec::BLAKE2S_256 => Self::Blake2s_256(Default::default()),
+            #[cfg(feature = "blake2")]
+            ssi_multicodec::BLAKE2B_8..=ssi_multicodec::BLAKE2B_512 => {
+                let output_len =
+                    crate::blake2::blake2b_output_len(codec).expect("programmer error");
+                Self::Blake2bVar(
+                    blake2::digest::VariableOutput::new(output_len).expect("programmer error"),
+                )
+            }
+            #[cfg(feature = "blake2")]
+            ssi_multicodec::BLAKE2S_8..=ssi_multicodec::BLAKE2S_256 => {
+                let output_len =
+                    crate::blake2::blake2s_output_len(codec).expect("programmer error");
+                Self::Blake2sVar(
+                    blake2::digest::VariableOutput::new(output_len).expect("programmer error"),
+                )
+            }
+            #[cfg(feature = "blake3")]
+            ssi_multicodec::BLAKE3 => Self::Blake3(blake3::Hasher::new()),
+            #[cfg(feature = "sha2")]
+            ssi_multicodec::SHA2_224 => Self::Sha_224(Default::default()),
+            #[cfg(feature = "sha2")]
+            ssi_multicodec::SHA2_256 => Self::Sha_256(Default::default()),
+            #[cfg(feature = "sha2")]
+            ssi_multicodec::SHA2_384 => Self::Sha_384(Default::default()),
+            #[cfg(feature = "sha2")]
+            ssi_multicodec::SHA2_512 => Self::Sha_512(Default::default()),
+            #[cfg(feature = "sha3")]
+            ssi_multicodec::SHA3_224 => Self::Sha3_224(Default::default()),
+            #[cfg(feature = "sha3")]
+            ssi_multicodec::SHA3_256 => Self::Sha3_256(Default::default()),
+            #[cfg(feature = "sha3")]
+            ssi_multicodec::SHA3_384 => Self::Sha3_384(Default::default()),
+            #[cfg(feature = "sha3")]
+            ssi_multicodec::SHA3_512 => Self::Sha3_512(Default::default()),
+            _ => {
+                bail!(
+                    kind: ErrorKind::UnsupportedCodec { codec },
+                    "Unsupported hash codec: 0x{:02x} (check that the feature providing it is enabled)",
+                    codec
+                );
+            }
+        };
+        Ok(hasher)
+    }
+    /// Returns the multihash codec of this hasher.
+    pub fn codec(&self) -> u64 {
+        match self {
+            #[cfg(feature = "blake2")]
+            Self::Blake2b_256(_) => ssi_multicodec::BLAKE2B_256,
+            #[cfg(feature = "blake2")]
+            Self::Blake2b_512(_) => ssi_multicodec::BLAKE2B_512,
+            #[cfg(feature = "blake2")]
+            Self::Blake2s_256(_) => ssi_multicodec::BLAKE2S_256,
+            #[cfg(feature = "blake2")]
+            Self::Blake2bVar(hasher) => {
+                crate::blake2::blake2b_codec(blake2::digest::VariableOutput::output_size(hasher))
+                    .expect("programmer error")
+            }
+            #[cfg(feature = "blake2")]
+            Self::Blake2sVar(hasher) => {
+                crate::blake2::blake2s_codec(blake2::digest::VariableOutput::output_size(hasher))
+                    .expect("programmer error")
+            }
+            #[cfg(feature = "blake3")]
+            Self::Blake3(_) => ssi_multicodec::BLAKE3,
+            #[cfg(feature = "sha2")]
+            Self::Sha_224(_) => ssi_multicodec::SHA2_224,
+            #[cfg(feature = "sha2")]
+            Self::Sha_256(_) => ssi_multicodec::SHA2_256,
+            #[cfg(feature = "sha2")]
+            Self::Sha_384(_) => ssi_multicodec::SHA2_384,
+            #[cfg(feature = "sha2")]
+            Self::Sha_512(_) => ssi_multicodec::SHA2_512,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_224(_) => ssi_multicodec::SHA3_224,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256(_) => ssi_multicodec::SHA3_256,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_384(_) => ssi_multicodec::SHA3_384,
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512(_) => ssi_multicodec::SHA3_512,
+        }
+    }
+    /// Feeds the given data into the hasher.
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            #[cfg(feature = "blake2")]
+            Self::Blake2b_256(hasher) => blake2::Digest::update(hasher, data),
+            #[cfg(feature = "blake2")]
+            Self::Blake2b_512(hasher) => blake2::Digest::update(hasher, data),
+            #[cfg(feature = "blake2")]
+            Self::Blake2s_256(hasher) => blake2::Digest::update(hasher, data),
+            #[cfg(feature = "blake2")]
+            Self::Blake2bVar(hasher) => blake2::digest::Update::update(hasher, data),
+            #[cfg(feature = "blake2")]
+            Self::Blake2sVar(hasher) => blake2::digest::Update::update(hasher, data),
+            #[cfg(feature = "blake3")]
+            Self::Blake3(hasher) => {
+                hasher.update(data);
+            }
+            #[cfg(feature = "sha2")]
+            Self::Sha_224(hasher) => sha2::Digest::update(hasher, data),
+            #[cfg(feature = "sha2")]
+            Self::Sha_256(hasher) => sha2::Digest::update(hasher, data),
+            #[cfg(feature = "sha2")]
+            Self::Sha_384(hasher) => sha2::Digest::update(hasher, data),
+            #[cfg(feature = "sha2")]
+            Self::Sha_512(hasher) => sha2::Digest::update(hasher, data),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_224(hasher) => sha3::Digest::update(hasher, data),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256(hasher) => sha3::Digest::update(hasher, data),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_384(hasher) => sha3::Digest::update(hasher, data),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512(hasher) => sha3::Digest::update(hasher, data),
+        }
+    }
+    /// Feeds all of the given reader's content into the hasher, using a large buffer.
+    pub fn update_reader(&mut self, mut reader: impl std::io::Read) -> std::io::Result<()> {
+        let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+        loop {
+            let n = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            self.update(&buffer[..n]);
+        }
+        Ok(())
+    }
+    /// Feeds the content of the file at the given path into the hasher.  For blake3, the file is
+    /// memory-mapped (and hashed using multiple threads if the "rayon" feature is enabled).  For other
+    /// hash functions, the file is read using a large buffer.
+    pub fn update_file(&mut self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        #[cfg(feature = "blake3")]
+        if let Self::Blake3(hasher) = self {
+            #[cfg(feature = "rayon")]
+            hasher.update_mmap_rayon(path)?;
+            #[cfg(not(feature = "rayon"))]
+            hasher.update_mmap(path)?;
+            return Ok(());
+        }
+        let file = std::fs::File::open(path)?;
+        self.update_reader(file)
+    }
+    /// Computes the hash of all the data fed into the hasher and returns it as an MBHash in the given base.
+    pub fn finalize(self, base: Base) -> MBHash {
+        match self {
+            #[cfg(feature = "blake2")]
+            Self::Blake2b_256(hasher) => MBHash::from_blake2b(base, hasher),
+            #[cfg(feature = "blake2")]
+            Self::Blake2b_512(hasher) => MBHash::from_blake2b(base, hasher),
+            #[cfg(feature = "blake2")]
+            Self::Blake2s_256(hasher) => MBHash::from_blake2s(base, hasher),
+            #[cfg(feature = "blake2")]
+            Self::Blake2bVar(hasher) => MBHash::from_blake2b_var(base, hasher),
+            #[cfg(feature = "blake2")]
+            Self::Blake2sVar(hasher) => MBHash::from_blake2s_var(base, hasher),
+            #[cfg(feature = "blake3")]
+            Self::Blake3(hasher) => MBHash::from_blake3(base, hasher),
+            #[cfg(feature = "sha2")]
+            Self::Sha_224(hasher) => MBHash::from_sha224(base, hasher),
+            #[cfg(feature = "sha2")]
+            Self::Sha_256(hasher) => MBHash::from_sha256(base, hasher),
+            #[cfg(feature = "sha2")]
+            Self::Sha_384(hasher) => MBHash::from_sha384(base, hasher),
+            #[cfg(feature = "sha2")]
+            Self::Sha_512(hasher) => MBHash::from_sha512(base, hasher),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_224(hasher) => MBHash::from_sha3_224(base, hasher),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256(hasher) => MBHash::from_sha3_256(base, hasher),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_384(hasher) => MBHash::from_sha3_384(base, hasher),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512(hasher) => MBHash::from_sha3_512(base, hasher),
+        }
+    }
+    /// Computes the hash of all the data fed into the hasher and returns the raw digest bytes.
+    pub(crate) fn finalize_digest(self) -> Vec<u8> {
+        match self {
+            #[cfg(feature = "blake2")]
+            Self::Blake2b_256(hasher) => blake2::Digest::finalize(hasher).to_vec(),
+            #[cfg(feature = "blake2")]
+            Self::Blake2b_512(hasher) => blake2::Digest::finalize(hasher).to_vec(),
+            #[cfg(feature = "blake2")]
+            Self::Blake2s_256(hasher) => blake2::Digest::finalize(hasher).to_vec(),
+            #[cfg(feature = "blake2")]
+            Self::Blake2bVar(hasher) => crate::blake2::finalize_variable(hasher),
+            #[cfg(feature = "blake2")]
+            Self::Blake2sVar(hasher) => crate::blake2::finalize_variable(hasher),
+            #[cfg(feature = "blake3")]
+            Self::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+            #[cfg(feature = "sha2")]
+            Self::Sha_224(hasher) => sha2::Digest::finalize(hasher).to_vec(),
+            #[cfg(feature = "sha2")]
+            Self::Sha_256(hasher) => sha2::Digest::finalize(hasher).to_vec(),
+            #[cfg(feature = "sha2")]
+            Self::Sha_384(hasher) => sha2::Digest::finalize(hasher).to_vec(),
+            #[cfg(feature = "sha2")]
+            Self::Sha_512(hasher) => sha2::Digest::finalize(hasher).to_vec(),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_224(hasher) => sha3::Digest::finalize(hasher).to_vec(),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_256(hasher) => sha3::Digest::finalize(hasher).to_vec(),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_384(hasher) => sha3::Digest::finalize(hasher).to_vec(),
+            #[cfg(feature = "sha3")]
+            Self::Sha3_512(hasher) => sha3::Digest::finalize(hasher).to_vec(),
+        }
+    }
+}
+
+impl MBHash {
+    /// Computes the hash of the file at the given path using the hash function given by the codec,
+    /// and returns it as an MBHash in the given base.  This gives the same result as feeding the file
+    /// content into a `Hasher` in chunks, but is faster for large files (see `Hasher::update_file`).
+    pub fn hash_file(path: impl AsRef<std::path::Path>, codec: u64, base: Base) -> Result<Self> {
+        let mut hasher = Hasher::new(codec)?;
+        hasher.update_file(path)?;
+        Ok(hasher.finalize(base))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Base, ErrorKind, Hasher, MBHash};
+
+    fn supported_codec_v() -> Vec<u64> {
+        let mut codec_v = Vec::new();
+        #[cfg(feature = "blake2")]
+        codec_v.extend([
+            ssi_multicodec::BLAKE2B_256,
+            ssi_multicodec::BLAKE2B_512,
+            ssi_multicodec::BLAKE2S_256,
+            // Non-power-of-two output lengths use the runtime-length hashers.
+            ssi_multicodec::BLAKE2B_8,
+            ssi_multicodec::BLAKE2B_160,
+            ssi_multicodec::BLAKE2S_160,
+        ]);
+        #[cfg(feature = "blake3")]
+        codec_v.push(ssi_multicodec::BLAKE3);
+        #[cfg(feature = "sha2")]
+        codec_v.extend([
+            ssi_multicodec::SHA2_224,
+            ssi_multicodec::SHA2_256,
+            ssi_multicodec::SHA2_384,
+            ssi_multicodec::SHA2_512,
+        ]);
+        #[cfg(feature = "sha3")]
+        codec_v.extend([
+            ssi_multicodec::SHA3_224,
+            ssi_multicodec::SHA3_256,
+            ssi_multicodec::SHA3_384,
+            ssi_multicodec::SHA3_512,
+        ]);
+        codec_v
+    }
+
+    #[test]
+    fn test_hasher_matches_from_fns() {
+        for codec in supported_codec_v() {
+            let mut hasher = Hasher::new(codec).expect("pass");
+            assert_eq!(hasher.codec(), codec);
+            hasher.update(b"HIP");
+            hasher.update(b"PO");
+            let digest = hasher.clone().finalize_digest();
+            let mb_hash = hasher.finalize(Base::Base64Url);
+            let multihash = mb_hash.decoded::<64>().expect("pass");
+            assert_eq!(multihash.code(), codec);
+            assert_eq!(multihash.digest(), digest.as_slice());
+            assert_eq!(
+                mb_hash,
+                MBHash::encoded::<64>(Base::Base64Url, codec, &digest).expect("pass")
+            );
+        }
+
+        for codec in [ssi_multicodec::ED25519_PUB, ssi_multicodec::BLAKE2B_8 - 1] {
+            let err = Hasher::new(codec).err().expect("pass");
+            assert_eq!(err.kind(), &ErrorKind::UnsupportedCodec { codec });
+        }
+    }
+
+    #[test]
+    fn test_hash_file_matches_streaming() {
+        // Make the content larger than the read buffer, and not a multiple of it.
+        let content: Vec<u8> = (0..(3 * super::READ_BUFFER_SIZE + 12345))
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let path = std::env::temp_dir().join(format!(
+            "mbx-test-hash-file-{}-{}",
+            std::process::id(),
+            rand::random::<u64>()
+        ));
+        std::fs::write(&path, &content).expect("pass");
+
+        for codec in supported_codec_v() {
+            let mb_hash = MBHash::hash_file(&path, codec, Base::Base64Url).expect("pass");
+            let mut hasher = Hasher::new(codec).expect("pass");
+            for chunk in content.chunks(1024) {
+                hasher.update(chunk);
+            }
+            assert_eq!(mb_hash, hasher.finalize(Base::Base64Url));
+        }
+
+        std::fs::remove_file(&path).expect("pass");
+        assert!(MBHash::hash_file(&path, ssi_multicodec::SHA2_256, Base::Base64Url).is_err());
+    }
+}
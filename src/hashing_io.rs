@@ -0,0 +1,206 @@
+use crate::{Base, Hasher, MBHash, MBHashStr, Result};
+use std::io::{Read, Write};
+
+/// A `std::io::Read` wrapper that passes through the inner reader's content while feeding it into a
+/// hasher, so that e.g. a file can be copied and hashed in the same pass.
+pub struct HashingReader<R> {
+    reader: R,
+    hasher: Hasher,
+}
+
+impl<R: Read> HashingReader<R> {
+    /// Creates a new hashing reader using the hash function given by the codec.
+    pub fn new(reader: R, codec: u64) -> Result<Self> {
+        Ok(Self {
+            reader,
+            hasher: Hasher::new(codec)?,
+        })
+    }
+    /// Returns a reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+    /// Computes the hash of everything read so far and returns it as an MBHash in the given base.
+    pub fn finalize(self, base: Base) -> MBHash {
+        self.hasher.finalize(base)
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.reader.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// A `std::io::Write` wrapper that passes through everything written to it to the inner writer while
+/// feeding it into a hasher, so that e.g. a file can be copied and hashed in the same pass.
+pub struct HashingWriter<W> {
+    writer: W,
+    hasher: Hasher,
+}
+
+impl<W: Write> HashingWriter<W> {
+    /// Creates a new hashing writer using the hash function given by the codec.
+    pub fn new(writer: W, codec: u64) -> Result<Self> {
+        Ok(Self {
+            writer,
+            hasher: Hasher::new(codec)?,
+        })
+    }
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+    /// Computes the hash of everything written so far and returns it as an MBHash in the given base.
+    /// Note that this drops the inner writer without flushing it, so call `flush` first if needed.
+    pub fn finalize(self, base: Base) -> MBHash {
+        self.hasher.finalize(base)
+    }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        // Only hash what the inner writer actually accepted.
+        let n = self.writer.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// A `std::io::Read` wrapper that passes through the inner reader's content while hashing it, and
+/// which fails with `std::io::ErrorKind::InvalidData` at EOF if the content does not match the
+/// expected MBHash.  Note that the content is passed through before it is verified, so the consumer
+/// must not act on it until EOF has been reached without error.
+pub struct VerifyingReader<R> {
+    reader: R,
+    verify_state: VerifyState,
+    expected_digest: Vec<u8>,
+}
+
+impl<R: Read> VerifyingReader<R> {
+    /// Creates a new verifying reader, using the hash function given by the codec of the expected MBHash.
+    pub fn new(reader: R, expected: &MBHashStr) -> Result<Self> {
+        let multihash = expected.decoded::<64>()?;
+        Ok(Self {
+            reader,
+            verify_state: VerifyState::Hashing(Hasher::new(multihash.code())?),
+            expected_digest: multihash.digest().to_vec(),
+        })
+    }
+    /// Returns the inner reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Read for VerifyingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.reader.read(buf)?;
+        if n > 0 {
+            self.verify_state.update(&buf[..n]);
+        } else if !buf.is_empty() {
+            // No bytes were read even though there was room for them, so this is EOF.
+            self.verify_state.verify_at_eof(&self.expected_digest)?;
+        }
+        Ok(n)
+    }
+}
+
+/// The verification state of a `VerifyingReader` or `AsyncVerifyingReader`.
+pub(crate) enum VerifyState {
+    /// EOF hasn't been reached yet, and the hasher has been fed everything read so far.
+    Hashing(Hasher),
+    /// EOF was reached and the content matched the expected digest.
+    Verified,
+    /// EOF was reached and the content did not match the expected digest.  This is sticky, so that
+    /// every read at EOF fails, not just the first one (e.g. if the caller retries or ignores the
+    /// first error).
+    Mismatch,
+}
+
+impl VerifyState {
+    /// Feeds the bytes read into the hasher.
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        if let Self::Hashing(hasher) = self {
+            hasher.update(data);
+        }
+    }
+    /// Checks the hash of the content against the expected digest the first time EOF is reached, and
+    /// fails with `std::io::ErrorKind::InvalidData` at every EOF after a mismatch.
+    pub(crate) fn verify_at_eof(&mut self, expected_digest: &[u8]) -> std::io::Result<()> {
+        let verify_state = std::mem::replace(self, Self::Mismatch);
+        *self = match verify_state {
+            Self::Hashing(hasher) => {
+                if hasher.finalize_digest() == expected_digest {
+                    Self::Verified
+                } else {
+                    Self::Mismatch
+                }
+            }
+            verify_state => verify_state,
+        };
+        if let Self::Mismatch = self {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "content does not match the expected MBHash",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "blake3"))]
+mod tests {
+    use crate::{Base, HashingReader, HashingWriter, MBHash, VerifyingReader};
+    use std::io::{Read, Write};
+
+    #[test]
+    fn test_hashing_reader_and_writer() {
+        let content = b"HIPPO".repeat(10000);
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&content);
+        let expected = MBHash::from_blake3(Base::Base64Url, hasher);
+
+        // Copy through both a HashingReader and a HashingWriter in one pass.
+        let mut reader =
+            HashingReader::new(content.as_slice(), ssi_multicodec::BLAKE3).expect("pass");
+        let mut writer = HashingWriter::new(Vec::new(), ssi_multicodec::BLAKE3).expect("pass");
+        std::io::copy(&mut reader, &mut writer).expect("pass");
+        writer.flush().expect("pass");
+        assert_eq!(writer.get_ref(), &content);
+        assert_eq!(reader.finalize(Base::Base64Url), expected);
+        assert_eq!(writer.finalize(Base::Base64Url), expected);
+
+        assert!(HashingReader::new(content.as_slice(), ssi_multicodec::ED25519_PUB).is_err());
+    }
+
+    #[test]
+    fn test_verifying_reader() {
+        let content = b"HIPPO".repeat(10000);
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&content);
+        let mb_hash = MBHash::from_blake3(Base::Base58Btc, hasher);
+
+        let mut reader = VerifyingReader::new(content.as_slice(), &mb_hash).expect("pass");
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).expect("pass");
+        assert_eq!(output, content);
+        assert_eq!(reader.read(&mut [0u8; 16]).expect("pass"), 0);
+
+        let tampered_content = b"OSTRICH".repeat(10000);
+        let mut reader = VerifyingReader::new(tampered_content.as_slice(), &mb_hash).expect("pass");
+        let mut output = Vec::new();
+        let err = reader.read_to_end(&mut output).expect_err("pass");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        // Reading again at EOF must still fail, rather than reporting a clean EOF.
+        for _ in 0..2 {
+            let err = reader.read(&mut [0u8; 16]).expect_err("pass");
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        }
+    }
+}
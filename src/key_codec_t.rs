@@ -0,0 +1,158 @@
+use crate::{ED448_PRIV_CODEC, MBPrivKeyStr, MBPubKeyStr, Result};
+
+/// A trait that is used to define the sigil for a specific key type within generics, analogous to
+/// `CodecCategorizableT` but for an exact pair of public and private key codecs.  This is used by
+/// `MBPubKeyOf` and `MBPrivKeyOf` so that a key type mismatch is a type error instead of a runtime
+/// error.
+///
+/// Validation invariant: every `MBPubKeyOf<K>` and `MBPrivKeyOf<K>` has passed `validate_pub_key` or
+/// `validate_priv_key` respectively, and when the backend feature for `K` is enabled (e.g. `p256`),
+/// those are implemented by converting into the backend's key type.  Hence the conversions from
+/// `MBPubKeyOf<K>` and `MBPrivKeyOf<K>` into the backend's key types are infallible, and the backend
+/// modules implement them with `From` rather than `TryFrom`.
+pub trait KeyCodecT:
+    Clone + Copy + std::fmt::Debug + Eq + std::hash::Hash + Ord + PartialEq + PartialOrd
+{
+    /// Returns the public key codec for this key type.
+    fn pub_key_codec() -> u64;
+    /// Returns the private key codec for this key type.
+    fn priv_key_codec() -> u64;
+    /// Validates the public key material beyond its codec and byte length.  If the feature for the
+    /// corresponding backend is enabled, this checks that the backend accepts the key, which is what
+    /// makes the conversions from `MBPubKeyOf<Self>` into the backend type infallible.
+    fn validate_pub_key(_mb_pub_key: &MBPubKeyStr) -> Result<()> {
+        Ok(())
+    }
+    /// Validates the private key material beyond its codec and byte length.  See `validate_pub_key`.
+    fn validate_priv_key(_mb_priv_key: &MBPrivKeyStr) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// The key type sigil for ed25519 keys (codecs ED25519_PUB and ED25519_PRIV).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ed25519;
+
+/// The key type sigil for ed448 keys (codecs ED448_PUB and ED448_PRIV).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ed448;
+
+/// The key type sigil for secp256k1 keys (codecs SECP256K1_PUB and SECP256K1_PRIV).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Secp256k1;
+
+/// The key type sigil for NIST P-256 keys (codecs P256_PUB and P256_PRIV).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct P256;
+
+/// The key type sigil for NIST P-384 keys (codecs P384_PUB and P384_PRIV).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct P384;
+
+/// The key type sigil for NIST P-521 keys (codecs P521_PUB and P521_PRIV).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct P521;
+
+impl KeyCodecT for Ed25519 {
+    fn pub_key_codec() -> u64 {
+        ssi_multicodec::ED25519_PUB
+    }
+    fn priv_key_codec() -> u64 {
+        ssi_multicodec::ED25519_PRIV
+    }
+    #[cfg(feature = "ed25519-dalek")]
+    fn validate_pub_key(mb_pub_key: &MBPubKeyStr) -> Result<()> {
+        ed25519_dalek::VerifyingKey::try_from(mb_pub_key).map(|_| ())
+    }
+    #[cfg(feature = "ed25519-dalek")]
+    fn validate_priv_key(mb_priv_key: &MBPrivKeyStr) -> Result<()> {
+        ed25519_dalek::SigningKey::try_from(mb_priv_key).map(|_| ())
+    }
+}
+
+impl KeyCodecT for Ed448 {
+    fn pub_key_codec() -> u64 {
+        ssi_multicodec::ED448_PUB
+    }
+    // NOTE: The codec ED448_PRIV is not yet supported by the ssi_multicodec crate,
+    // hence the hardcoded value.  See https://github.com/multiformats/multicodec/pull/390
+    fn priv_key_codec() -> u64 {
+        ED448_PRIV_CODEC
+    }
+    #[cfg(feature = "ed448-goldilocks")]
+    fn validate_pub_key(mb_pub_key: &MBPubKeyStr) -> Result<()> {
+        ed448_goldilocks::VerifyingKey::try_from(mb_pub_key).map(|_| ())
+    }
+    #[cfg(feature = "ed448-goldilocks")]
+    fn validate_priv_key(mb_priv_key: &MBPrivKeyStr) -> Result<()> {
+        ed448_goldilocks::SigningKey::try_from(mb_priv_key).map(|_| ())
+    }
+}
+
+impl KeyCodecT for Secp256k1 {
+    fn pub_key_codec() -> u64 {
+        ssi_multicodec::SECP256K1_PUB
+    }
+    fn priv_key_codec() -> u64 {
+        ssi_multicodec::SECP256K1_PRIV
+    }
+    #[cfg(feature = "k256")]
+    fn validate_pub_key(mb_pub_key: &MBPubKeyStr) -> Result<()> {
+        k256::ecdsa::VerifyingKey::try_from(mb_pub_key).map(|_| ())
+    }
+    #[cfg(feature = "k256")]
+    fn validate_priv_key(mb_priv_key: &MBPrivKeyStr) -> Result<()> {
+        k256::ecdsa::SigningKey::try_from(mb_priv_key).map(|_| ())
+    }
+}
+
+impl KeyCodecT for P256 {
+    fn pub_key_codec() -> u64 {
+        ssi_multicodec::P256_PUB
+    }
+    fn priv_key_codec() -> u64 {
+        ssi_multicodec::P256_PRIV
+    }
+    #[cfg(feature = "p256")]
+    fn validate_pub_key(mb_pub_key: &MBPubKeyStr) -> Result<()> {
+        p256::ecdsa::VerifyingKey::try_from(mb_pub_key).map(|_| ())
+    }
+    #[cfg(feature = "p256")]
+    fn validate_priv_key(mb_priv_key: &MBPrivKeyStr) -> Result<()> {
+        p256::ecdsa::SigningKey::try_from(mb_priv_key).map(|_| ())
+    }
+}
+
+impl KeyCodecT for P384 {
+    fn pub_key_codec() -> u64 {
+        ssi_multicodec::P384_PUB
+    }
+    fn priv_key_codec() -> u64 {
+        ssi_multicodec::P384_PRIV
+    }
+    #[cfg(feature = "p384")]
+    fn validate_pub_key(mb_pub_key: &MBPubKeyStr) -> Result<()> {
+        p384::ecdsa::VerifyingKey::try_from(mb_pub_key).map(|_| ())
+    }
+    #[cfg(feature = "p384")]
+    fn validate_priv_key(mb_priv_key: &MBPrivKeyStr) -> Result<()> {
+        p384::ecdsa::SigningKey::try_from(mb_priv_key).map(|_| ())
+    }
+}
+
+impl KeyCodecT for P521 {
+    fn pub_key_codec() -> u64 {
+        ssi_multicodec::P521_PUB
+    }
+    fn priv_key_codec() -> u64 {
+        ssi_multicodec::P521_PRIV
+    }
+    #[cfg(feature = "p521")]
+    fn validate_pub_key(mb_pub_key: &MBPubKeyStr) -> Result<()> {
+        p521::ecdsa::VerifyingKey::try_from(mb_pub_key).map(|_| ())
+    }
+    #[cfg(feature = "p521")]
+    fn validate_priv_key(mb_priv_key: &MBPrivKeyStr) -> Result<()> {
+        p521::ecdsa::SigningKey::try_from(mb_priv_key).map(|_| ())
+    }
+}
@@ -1,24 +1,66 @@
+#[cfg(all(
+    any(feature = "async", feature = "futures"),
+    any(
+        feature = "blake2",
+        feature = "blake3",
+        feature = "sha2",
+        feature = "sha3"
+    )
+))]
+mod async_hashing;
+#[cfg(feature = "blake2")]
+mod blake2;
 #[cfg(feature = "blake3")]
 mod blake3;
 mod codec_categorizable_t;
 mod codec_category;
+mod codec_info;
 #[cfg(feature = "codec-str")]
 mod codec_str;
+mod diagnosis;
 #[cfg(feature = "ed25519-dalek")]
 mod ed25519_dalek;
 #[cfg(feature = "ed448-goldilocks")]
 mod ed448_goldilocks;
 mod error;
+#[cfg(any(
+    feature = "blake2",
+    feature = "blake3",
+    feature = "sha2",
+    feature = "sha3"
+))]
+mod hasher;
+#[cfg(any(
+    feature = "blake2",
+    feature = "blake3",
+    feature = "sha2",
+    feature = "sha3"
+))]
+mod hashing_io;
 #[cfg(feature = "k256")]
 mod k256;
+mod key_codec_t;
+mod mb_any;
+mod mb_any_str;
 mod mb_hash;
 mod mb_hash_str;
 mod mb_priv_key;
+mod mb_priv_key_of;
 mod mb_priv_key_str;
 mod mb_pub_key;
+mod mb_pub_key_of;
 mod mb_pub_key_str;
+mod mb_value;
+mod mb_value_ref;
 mod mbx;
 mod mbx_str;
+#[cfg(any(
+    feature = "blake2",
+    feature = "blake3",
+    feature = "sha2",
+    feature = "sha3"
+))]
+mod merkle;
 #[cfg(feature = "p256")]
 mod p256;
 #[cfg(feature = "p384")]
@@ -32,22 +74,74 @@ mod sha3;
 #[cfg(feature = "signature-dyn")]
 mod signature_dyn;
 
+#[cfg(all(
+    feature = "async",
+    any(
+        feature = "blake2",
+        feature = "blake3",
+        feature = "sha2",
+        feature = "sha3"
+    )
+))]
+pub use async_hashing::hash_async_reader;
+#[cfg(all(
+    feature = "futures",
+    any(
+        feature = "blake2",
+        feature = "blake3",
+        feature = "sha2",
+        feature = "sha3"
+    )
+))]
+pub use async_hashing::hash_futures_reader;
+#[cfg(all(
+    any(feature = "async", feature = "futures"),
+    any(
+        feature = "blake2",
+        feature = "blake3",
+        feature = "sha2",
+        feature = "sha3"
+    )
+))]
+pub use async_hashing::{AsyncHashingSink, AsyncVerifyingReader};
 #[cfg(feature = "codec-str")]
-pub use codec_str::codec_str;
+pub use codec_str::{codec_from_str, codec_str};
 pub(crate) use mbx_str::mbx_str_validate_impl;
 pub use {
-    codec_categorizable_t::{CodecCategorizableT, PrivKeyCategory, PubKeyCategory},
+    codec_categorizable_t::{
+        AnyCategory, CodecCategorizableT, PrivKeyCategory, PubKeyCategory, SignatureCategory,
+    },
     codec_category::CodecCategory,
-    error::Error,
+    codec_info::{CodecInfo, CodecName, codec_info, codec_info_table},
+    diagnosis::Diagnosis,
+    error::{Error, ErrorKind},
+    key_codec_t::{Ed448, Ed25519, KeyCodecT, P256, P384, P521, Secp256k1},
+    mb_any::MBAny,
+    mb_any_str::MBAnyStr,
     mb_hash::MBHash,
     mb_hash_str::MBHashStr,
     mb_priv_key::MBPrivKey,
+    mb_priv_key_of::MBPrivKeyOf,
     mb_priv_key_str::MBPrivKeyStr,
     mb_pub_key::MBPubKey,
+    mb_pub_key_of::MBPubKeyOf,
     mb_pub_key_str::MBPubKeyStr,
+    mb_value::MBValue,
+    mb_value_ref::MBValueRef,
     mbx::MBX,
     mbx_str::MBXStr,
 };
+#[cfg(any(
+    feature = "blake2",
+    feature = "blake3",
+    feature = "sha2",
+    feature = "sha3"
+))]
+pub use {
+    hasher::Hasher,
+    hashing_io::{HashingReader, HashingWriter, VerifyingReader},
+    merkle::{MerkleProof, MerkleTree},
+};
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// Defines the various available base-encodings.  The recommended base-encoding is Base::Base64Url.
@@ -0,0 +1,12 @@
+use crate::{AnyCategory, MBAnyStr, MBX};
+
+/// This newtype is a String representing any well-formed `multibase(base, varint(codec) || bytes)`,
+/// regardless of the codec, e.g. for forwarding a multikey whose key type isn't known.
+/// See also `MBAnyStr` and `AnyCategory`.
+pub type MBAny = MBX<AnyCategory>;
+
+impl MBAny {
+    pub fn as_mb_any_str(&self) -> &MBAnyStr {
+        self.as_mbx_str()
+    }
+}
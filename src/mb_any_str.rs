@@ -0,0 +1,40 @@
+use crate::{AnyCategory, MBXStr};
+
+/// This newtype is a str representing any well-formed `multibase(base, varint(codec) || bytes)`,
+/// regardless of the codec.  See also `MBAny` and `AnyCategory`.
+pub type MBAnyStr = MBXStr<AnyCategory>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{Base, ErrorKind, MBAny, MBAnyStr, MBPubKeyStr};
+
+    #[test]
+    fn test_mb_any_str() {
+        // An ed25519 public key with a truncated key, a private key, and an unknown codec are all accepted.
+        for (codec, byte_v) in [
+            (ssi_multicodec::ED25519_PUB, vec![0u8; 31]),
+            (ssi_multicodec::ED25519_PRIV, vec![0u8; 32]),
+            (0x1234567, b"HIPPO".to_vec()),
+            (ssi_multicodec::SHA2_256, vec![]),
+        ] {
+            let mb_any = MBAny::encoded(Base::Base64Url, codec, &byte_v).expect("pass");
+            println!("mb_any: {}", mb_any);
+            let mb_any_str = MBAnyStr::new_ref(mb_any.as_str()).expect("pass");
+            let decoded = mb_any_str.decoded().expect("pass");
+            assert_eq!(decoded.codec(), codec);
+            assert_eq!(decoded.data(), byte_v.as_slice());
+        }
+
+        // Malformed values are still rejected.
+        let err = MBAnyStr::new_ref("!abc").expect_err("pass");
+        assert_eq!(err.kind(), &ErrorKind::InvalidBase);
+        let truncated_varint = multibase::encode(Base::Base58Btc, [0x80u8]);
+        let err = MBAnyStr::new_ref(&truncated_varint).expect_err("pass");
+        assert_eq!(err.kind(), &ErrorKind::InvalidVarint);
+
+        // An MBPubKeyStr is always a valid MBAnyStr.
+        let mb_pub_key =
+            MBPubKeyStr::new_ref("z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp").expect("pass");
+        assert!(MBAnyStr::new_ref(mb_pub_key.as_str()).is_ok());
+    }
+}
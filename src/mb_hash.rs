@@ -13,6 +13,10 @@ use crate::{Base, MBHashStr, Multihash, Result};
 pub struct MBHash(String);
 
 impl MBHash {
+    /// Wraps a string that the caller has already validated as an `MBHashStr`.
+    pub(crate) fn from_validated_string(s: String) -> Self {
+        Self(s)
+    }
     pub fn from_multihash<const SIZE: usize>(
         base: Base,
         multihash: Multihash<SIZE>,
@@ -1,4 +1,4 @@
-use crate::{Base, CodecCategory, Error, Multihash, ensure};
+use crate::{Base, CodecCategory, CodecName, Error, ErrorKind, Multihash, ensure};
 
 /// This newtype is a str that is defined to be `multibase(base, multihash(codec, digest))`, where
 /// `multihash(codec, digest)` is defined to be `varint(codec) || varint(digest.len()) || digest`.
@@ -13,6 +13,11 @@ use crate::{Base, CodecCategory, Error, Multihash, ensure};
 pub struct MBHashStr(str);
 
 impl MBHashStr {
+    /// Wraps a str that the caller has already validated as an `MBHashStr`.
+    pub(crate) fn from_validated_str(s: &str) -> &Self {
+        // SAFETY: MBHashStr is `#[repr(transparent)]` over `str`, and the caller has validated `s`.
+        unsafe { &*(s as *const str as *const Self) }
+    }
     /// Returns the base of this MBHashStr.
     pub fn base(&self) -> Base {
         Base::from_code(self.base_char()).expect("programmer error")
@@ -40,8 +45,12 @@ impl pneutype::Validate for MBHashStr {
         let codec_category = CodecCategory::from_codec(multihash.code());
         ensure!(
             codec_category == CodecCategory::Multihash,
-            "expected codec (0x{:02x}) to be in category {:?} but it was in category {:?}",
-            multihash.code(),
+            kind: ErrorKind::WrongCodecCategory {
+                expected: CodecCategory::Multihash,
+                actual: codec_category,
+            },
+            "expected codec {} to be in category {:?} but it was in category {:?}",
+            CodecName(multihash.code()),
             CodecCategory::Multihash,
             codec_category,
         );
@@ -14,6 +14,10 @@ use crate::{Error, MBPrivKeyStr};
 pub struct MBPrivKey(String);
 
 impl MBPrivKey {
+    /// Wraps a string that the caller has already validated as an `MBPrivKeyStr`.
+    pub(crate) fn from_validated_string(s: String) -> Self {
+        Self(s)
+    }
     // Note that this doesn't actually check the bytes against the codec (at the very least it should check the
     // length of the bytes against the codec).  TODO: Do this check.
     pub fn encoded(base: multibase::Base, codec: u64, byte_v: &[u8]) -> Result<Self, Error> {
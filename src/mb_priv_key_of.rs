@@ -0,0 +1,109 @@
+use crate::{
+    CodecName, Error, ErrorKind, KeyCodecT, MBPrivKey, MBPrivKeyStr, PrivKeyCategory, ensure,
+    mbx_str_validate_impl,
+};
+
+/// This newtype is an `MBPrivKey` whose codec is known (by construction) to be the private key codec
+/// of the key type `K`, e.g. `MBPrivKeyOf<P256>`.  If the backend feature for `K` is enabled (e.g.
+/// `p256`), the key material is also validated by the backend, so the conversions into the backend's
+/// signing key type are infallible (see the validation invariant of `KeyCodecT`).  Like `MBPrivKey`,
+/// this does not impl `std::fmt::Display`, and its `std::fmt::Debug` impl redacts the content.  See
+/// also `MBPubKeyOf`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(into = "MBPrivKey", try_from = "MBPrivKey"))]
+pub struct MBPrivKeyOf<K: KeyCodecT>(std::marker::PhantomData<K>, MBPrivKey);
+
+impl<K: KeyCodecT> MBPrivKeyOf<K> {
+    /// Encodes the given private key bytes using the private key codec of `K`.
+    pub fn encoded(base: multibase::Base, byte_v: &[u8]) -> Result<Self, Error> {
+        Self::try_from(MBPrivKey::encoded(base, K::priv_key_codec(), byte_v)?)
+    }
+    pub fn as_mb_priv_key(&self) -> &MBPrivKey {
+        &self.1
+    }
+    pub fn as_mb_priv_key_str(&self) -> &MBPrivKeyStr {
+        self.1.as_mb_priv_key_str()
+    }
+    pub fn into_mb_priv_key(self) -> MBPrivKey {
+        self.1
+    }
+}
+
+impl<K: KeyCodecT> AsRef<MBPrivKeyStr> for MBPrivKeyOf<K> {
+    fn as_ref(&self) -> &MBPrivKeyStr {
+        self.as_mb_priv_key_str()
+    }
+}
+
+impl<K: KeyCodecT> std::ops::Deref for MBPrivKeyOf<K> {
+    type Target = MBPrivKeyStr;
+    fn deref(&self) -> &Self::Target {
+        self.as_mb_priv_key_str()
+    }
+}
+
+impl<K: KeyCodecT> From<MBPrivKeyOf<K>> for MBPrivKey {
+    fn from(mb_priv_key_of: MBPrivKeyOf<K>) -> Self {
+        mb_priv_key_of.1
+    }
+}
+
+impl<K: KeyCodecT> TryFrom<MBPrivKey> for MBPrivKeyOf<K> {
+    type Error = Error;
+    fn try_from(mb_priv_key: MBPrivKey) -> Result<Self, Self::Error> {
+        // The owned type's constructors (e.g. `encoded`) don't check the byte length, so validate
+        // everything here.
+        mbx_str_validate_impl::<PrivKeyCategory>(mb_priv_key.as_str())?;
+        let codec = mb_priv_key.as_mb_priv_key_str().decoded()?.codec();
+        ensure!(
+            codec == K::priv_key_codec(),
+            kind: ErrorKind::WrongCodec {
+                expected: K::priv_key_codec(),
+                actual: codec,
+            },
+            "Expected codec {}, got {}",
+            CodecName(K::priv_key_codec()),
+            CodecName(codec)
+        );
+        K::validate_priv_key(mb_priv_key.as_mb_priv_key_str())?;
+        Ok(Self(std::marker::PhantomData, mb_priv_key))
+    }
+}
+
+impl<K: KeyCodecT> std::str::FromStr for MBPrivKeyOf<K> {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(MBPrivKey::try_from(s.to_string())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Base, Ed448, Ed25519, ErrorKind, MBPrivKey, MBPrivKeyOf};
+
+    #[test]
+    fn test_mb_priv_key_of() {
+        let mb_priv_key_of =
+            MBPrivKeyOf::<Ed25519>::encoded(Base::Base64Url, &[0x07; 32]).expect("pass");
+        println!("mb_priv_key_of: {:?}", mb_priv_key_of);
+        assert!(
+            !format!("{:?}", mb_priv_key_of).contains(mb_priv_key_of.as_mb_priv_key().as_str())
+        );
+        assert_eq!(
+            mb_priv_key_of.decoded().expect("pass").codec(),
+            ssi_multicodec::ED25519_PRIV
+        );
+
+        let mb_priv_key = MBPrivKey::from(mb_priv_key_of);
+        let err = MBPrivKeyOf::<Ed448>::try_from(mb_priv_key).expect_err("pass");
+        println!("err: {}", err);
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::WrongCodec {
+                expected: crate::ED448_PRIV_CODEC,
+                actual: ssi_multicodec::ED25519_PRIV,
+            }
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    ED448_PRIV_CODEC, Error, MBPubKey, PrivKeyCategory, Result, bail, mbx_str_validate_impl,
+    ED448_PRIV_CODEC, Error, ErrorKind, MBPubKey, PrivKeyCategory, Result, bail,
+    mbx_str_validate_impl,
 };
 
 /// This newtype is a str representing a publicKeyMultibase value (see <https://www.w3.org/TR/cid-1.0/#Multikey>).
@@ -11,6 +12,11 @@ use crate::{
 pub struct MBPrivKeyStr(str);
 
 impl MBPrivKeyStr {
+    /// Wraps a str that the caller has already validated as an `MBPrivKeyStr`.
+    pub(crate) fn from_validated_str(s: &str) -> &Self {
+        // SAFETY: MBPrivKeyStr is `#[repr(transparent)]` over `str`, and the caller has validated `s`.
+        unsafe { &*(s as *const str as *const Self) }
+    }
     /// Returns the base of this MBPrivKeyStr.
     pub fn base(&self) -> multibase::Base {
         multibase::Base::from_code(self.base_char()).expect("programmer error")
@@ -27,9 +33,9 @@ impl MBPrivKeyStr {
     #[cfg(feature = "signature-dyn")]
     pub fn key_type(&self) -> Result<signature_dyn::KeyType> {
         // TODO: Do this without allocation.
-        Ok(signature_dyn::KeyType::try_from_priv_key_codec(
-            self.decoded()?.codec(),
-        )?)
+        let codec = self.decoded()?.codec();
+        signature_dyn::KeyType::try_from_priv_key_codec(codec)
+            .map_err(|e| Error::from(e).with_kind(ErrorKind::UnsupportedCodec { codec }))
     }
     #[cfg(feature = "signature-dyn")]
     pub fn to_signer_bytes(&self) -> Result<signature_dyn::SignerBytes> {
@@ -50,6 +56,9 @@ impl MBPrivKeyStr {
                 #[cfg(not(feature = "ed25519-dalek"))]
                 {
                     bail!(
+                        kind: ErrorKind::FeatureDisabled {
+                            feature: "ed25519-dalek"
+                        },
                         "MBPrivKeyStr::mb_pub_key is only implemented for ed25519 key type if the \"ed25519-dalek\" feature is enabled"
                     );
                 }
@@ -69,6 +78,9 @@ impl MBPrivKeyStr {
                 #[cfg(not(feature = "ed448-goldilocks"))]
                 {
                     bail!(
+                        kind: ErrorKind::FeatureDisabled {
+                            feature: "ed448-goldilocks"
+                        },
                         "MBPrivKeyStr::mb_pub_key is only implemented for ed448 key type if the \"ed448-goldilocks\" feature is enabled"
                     );
                 }
@@ -85,6 +97,9 @@ impl MBPrivKeyStr {
                 #[cfg(not(feature = "k256"))]
                 {
                     bail!(
+                        kind: ErrorKind::FeatureDisabled {
+                            feature: "k256"
+                        },
                         "MBPrivKeyStr::mb_pub_key is only implemented for secp256k1 key type if the \"k256\" feature is enabled"
                     );
                 }
@@ -101,6 +116,9 @@ impl MBPrivKeyStr {
                 #[cfg(not(feature = "p256"))]
                 {
                     bail!(
+                        kind: ErrorKind::FeatureDisabled {
+                            feature: "p256"
+                        },
                         "MBPrivKeyStr::mb_pub_key is only implemented for p256 key type if the \"p256\" feature is enabled"
                     );
                 }
@@ -117,6 +135,9 @@ impl MBPrivKeyStr {
                 #[cfg(not(feature = "p384"))]
                 {
                     bail!(
+                        kind: ErrorKind::FeatureDisabled {
+                            feature: "p384"
+                        },
                         "MBPrivKeyStr::mb_pub_key is only implemented for p384 key type if the \"p384\" feature is enabled"
                     );
                 }
@@ -133,12 +154,21 @@ impl MBPrivKeyStr {
                 #[cfg(not(feature = "p521"))]
                 {
                     bail!(
+                        kind: ErrorKind::FeatureDisabled {
+                            feature: "p521"
+                        },
                         "MBPrivKeyStr::mb_pub_key is only implemented for p521 key type if the \"p521\" feature is enabled"
                     );
                 }
             }
             _ => {
-                bail!("Unsupported codec: 0x{:02x}", decoded.codec());
+                bail!(
+                    kind: ErrorKind::UnsupportedCodec {
+                        codec: decoded.codec()
+                    },
+                    "Unsupported codec: 0x{:02x}",
+                    decoded.codec()
+                );
             }
         }
     }
@@ -154,7 +184,6 @@ impl pneutype::Validate for MBPrivKeyStr {
     type Data = str;
     type Error = Error;
     fn validate(data: &Self::Data) -> std::result::Result<(), Self::Error> {
-        use crate::CodecCategorizableT;
-        mbx_str_validate_impl(data, PrivKeyCategory::codec_category())
+        mbx_str_validate_impl::<PrivKeyCategory>(data)
     }
 }
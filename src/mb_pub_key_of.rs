@@ -0,0 +1,118 @@
+use crate::{
+    CodecName, Error, ErrorKind, KeyCodecT, MBPubKey, MBPubKeyStr, PubKeyCategory, ensure,
+    mbx_str_validate_impl,
+};
+
+/// This newtype is an `MBPubKey` whose codec is known (by construction) to be the public key codec
+/// of the key type `K`, e.g. `MBPubKeyOf<Ed25519>`, so that APIs that require a specific key type can
+/// express that in their signature instead of re-checking the codec at runtime.  If the backend
+/// feature for `K` is enabled (e.g. `ed25519-dalek`), the key material is also validated by the
+/// backend, so the conversions into the backend's verifying key type are infallible (see the
+/// validation invariant of `KeyCodecT`).  See also `MBPrivKeyOf`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(into = "MBPubKey", try_from = "MBPubKey"))]
+pub struct MBPubKeyOf<K: KeyCodecT>(std::marker::PhantomData<K>, MBPubKey);
+
+impl<K: KeyCodecT> MBPubKeyOf<K> {
+    /// Encodes the given public key bytes using the public key codec of `K`.
+    pub fn encoded(base: multibase::Base, byte_v: &[u8]) -> Result<Self, Error> {
+        Self::try_from(MBPubKey::encoded(base, K::pub_key_codec(), byte_v)?)
+    }
+    pub fn as_mb_pub_key(&self) -> &MBPubKey {
+        &self.1
+    }
+    pub fn as_mb_pub_key_str(&self) -> &MBPubKeyStr {
+        self.1.as_mb_pub_key_str()
+    }
+    pub fn into_mb_pub_key(self) -> MBPubKey {
+        self.1
+    }
+}
+
+impl<K: KeyCodecT> AsRef<MBPubKeyStr> for MBPubKeyOf<K> {
+    fn as_ref(&self) -> &MBPubKeyStr {
+        self.as_mb_pub_key_str()
+    }
+}
+
+impl<K: KeyCodecT> std::ops::Deref for MBPubKeyOf<K> {
+    type Target = MBPubKeyStr;
+    fn deref(&self) -> &Self::Target {
+        self.as_mb_pub_key_str()
+    }
+}
+
+impl<K: KeyCodecT> std::fmt::Display for MBPubKeyOf<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.1.fmt(f)
+    }
+}
+
+impl<K: KeyCodecT> From<MBPubKeyOf<K>> for MBPubKey {
+    fn from(mb_pub_key_of: MBPubKeyOf<K>) -> Self {
+        mb_pub_key_of.1
+    }
+}
+
+impl<K: KeyCodecT> TryFrom<MBPubKey> for MBPubKeyOf<K> {
+    type Error = Error;
+    fn try_from(mb_pub_key: MBPubKey) -> Result<Self, Self::Error> {
+        // The owned type's constructors (e.g. `encoded`) don't check the byte length, so validate
+        // everything here.
+        mbx_str_validate_impl::<PubKeyCategory>(mb_pub_key.as_str())?;
+        let codec = mb_pub_key.as_mb_pub_key_str().decoded()?.codec();
+        ensure!(
+            codec == K::pub_key_codec(),
+            kind: ErrorKind::WrongCodec {
+                expected: K::pub_key_codec(),
+                actual: codec,
+            },
+            "Expected codec {}, got {}",
+            CodecName(K::pub_key_codec()),
+            CodecName(codec)
+        );
+        K::validate_pub_key(mb_pub_key.as_mb_pub_key_str())?;
+        Ok(Self(std::marker::PhantomData, mb_pub_key))
+    }
+}
+
+impl<K: KeyCodecT> std::str::FromStr for MBPubKeyOf<K> {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(MBPubKey::try_from(s.to_string())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Base, ErrorKind, MBPubKey, MBPubKeyOf, P256, Secp256k1};
+
+    #[test]
+    fn test_mb_pub_key_of() {
+        let mb_pub_key_of = "zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme"
+            .parse::<MBPubKeyOf<Secp256k1>>()
+            .expect("pass");
+        println!("mb_pub_key_of: {}", mb_pub_key_of);
+        assert_eq!(
+            mb_pub_key_of.decoded().expect("pass").codec(),
+            ssi_multicodec::SECP256K1_PUB
+        );
+        let mb_pub_key = MBPubKey::from(mb_pub_key_of.clone());
+        assert_eq!(mb_pub_key.as_mb_pub_key_str(), &*mb_pub_key_of);
+
+        // A valid public key of a different key type is rejected.
+        let err = MBPubKeyOf::<P256>::try_from(mb_pub_key).expect_err("pass");
+        println!("err: {}", err);
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::WrongCodec {
+                expected: ssi_multicodec::P256_PUB,
+                actual: ssi_multicodec::SECP256K1_PUB,
+            }
+        );
+
+        // The byte length is still checked.
+        assert!(MBPubKeyOf::<P256>::encoded(Base::Base58Btc, &[0x02; 32]).is_err());
+    }
+}
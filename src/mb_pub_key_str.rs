@@ -7,9 +7,10 @@ pub type MBPubKeyStr = MBXStr<PubKeyCategory>;
 impl MBPubKeyStr {
     #[cfg(feature = "signature-dyn")]
     pub fn try_into_key_type(&self) -> crate::Result<signature_dyn::KeyType> {
-        Ok(signature_dyn::KeyType::try_from_pub_key_codec(
-            self.decoded().expect("programmer error").codec(),
-        )?)
+        let codec = self.decoded().expect("programmer error").codec();
+        signature_dyn::KeyType::try_from_pub_key_codec(codec).map_err(|e| {
+            crate::Error::from(e).with_kind(crate::ErrorKind::UnsupportedCodec { codec })
+        })
     }
     #[cfg(feature = "signature-dyn")]
     pub fn to_verifier_bytes(&self) -> crate::Result<signature_dyn::VerifierBytes<'_>> {
@@ -0,0 +1,212 @@
+use crate::{
+    CodecCategorizableT, CodecCategory, Error, MBAny, MBHash, MBPrivKey, MBPubKey, MBValueRef, MBX,
+    Multihash, PrivKeyCategory, PubKeyCategory, Result, SignatureCategory,
+};
+
+/// A multibase value whose type is determined at runtime from its codec category, e.g. for a JSON
+/// field that may hold a hash, a public key, a private key, or a signature.  Codecs in any other
+/// category (including unknown codecs) are parsed as `MBValue::Other`.  See also `MBValueRef`.
+/// Note that, as with `MBPrivKey`, the `std::fmt::Debug` impl redacts private keys.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MBValue {
+    Hash(MBHash),
+    PubKey(MBPubKey),
+    PrivKey(MBPrivKey),
+    Signature(MBX<SignatureCategory>),
+    Other(MBAny),
+}
+
+impl MBValue {
+    /// Parses the string, decoding it only once to determine its codec category and validate it as
+    /// the corresponding type.
+    pub fn parse(s: &str) -> Result<Self> {
+        Self::try_from(s.to_string())
+    }
+    /// Returns the codec category of this value, which determines the variant.  Note that `Other` may
+    /// be any category other than the ones having their own variants.
+    pub fn codec_category(&self) -> CodecCategory {
+        self.as_value_ref().codec_category()
+    }
+    /// Returns the multibase string.
+    pub fn as_str(&self) -> &str {
+        self.as_value_ref().as_str()
+    }
+    /// Returns the borrowed form of this value.
+    pub fn as_value_ref(&self) -> MBValueRef<'_> {
+        match self {
+            Self::Hash(mb_hash) => MBValueRef::Hash(mb_hash.as_mb_hash_str()),
+            Self::PubKey(mb_pub_key) => MBValueRef::PubKey(mb_pub_key.as_mb_pub_key_str()),
+            Self::PrivKey(mb_priv_key) => MBValueRef::PrivKey(mb_priv_key.as_mb_priv_key_str()),
+            Self::Signature(mb_signature) => MBValueRef::Signature(mb_signature.as_mbx_str()),
+            Self::Other(mb_any) => MBValueRef::Other(mb_any.as_mb_any_str()),
+        }
+    }
+}
+
+/// Decodes the string once, validates it as the type corresponding to its codec category, and returns
+/// that codec category.  This is shared by `MBValue` and `MBValueRef`, which then construct the
+/// validated type without decoding the string again.
+pub(crate) fn validate_mb_value(s: &str) -> Result<CodecCategory> {
+    let (_base, byte_v) = multibase::decode(s)?;
+    let multi_encoded = ssi_multicodec::MultiEncodedBuf::new(byte_v)?;
+    let codec = multi_encoded.codec();
+    let codec_category = CodecCategory::from_codec(codec);
+    match codec_category {
+        CodecCategory::Multihash => {
+            // Use 64 here to accomodate the largest expected digest size (SHA2-512 is 64 bytes).
+            Multihash::<64>::from_bytes(multi_encoded.as_bytes())?;
+        }
+        CodecCategory::PubKey => {
+            PubKeyCategory::validate_codec_data(codec, multi_encoded.data())?;
+        }
+        CodecCategory::PrivKey => {
+            PrivKeyCategory::validate_codec_data(codec, multi_encoded.data())?;
+        }
+        CodecCategory::Signature => {
+            SignatureCategory::validate_codec_data(codec, multi_encoded.data())?;
+        }
+        CodecCategory::SymmetricKey | CodecCategory::Unspecified => {}
+    }
+    Ok(codec_category)
+}
+
+impl TryFrom<String> for MBValue {
+    type Error = Error;
+    fn try_from(s: String) -> Result<Self> {
+        Ok(match validate_mb_value(&s)? {
+            CodecCategory::Multihash => Self::Hash(MBHash::from_validated_string(s)),
+            CodecCategory::PubKey => Self::PubKey(MBPubKey::from_validated_string(s)),
+            CodecCategory::PrivKey => Self::PrivKey(MBPrivKey::from_validated_string(s)),
+            CodecCategory::Signature => Self::Signature(MBX::from_validated_string(s)),
+            CodecCategory::SymmetricKey | CodecCategory::Unspecified => {
+                Self::Other(MBAny::from_validated_string(s))
+            }
+        })
+    }
+}
+
+impl std::str::FromStr for MBValue {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl From<MBValueRef<'_>> for MBValue {
+    fn from(mb_value_ref: MBValueRef<'_>) -> Self {
+        mb_value_ref.to_value()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MBValue {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MBValue {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::try_from(s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Base, CodecCategory, ErrorKind, MBPrivKey, MBValue, MBValueRef};
+
+    fn encode(codec: u64, byte_v: &[u8]) -> String {
+        let multi_encoded = ssi_multicodec::MultiEncodedBuf::encode_bytes(codec, byte_v);
+        multibase::encode(Base::Base58Btc, multi_encoded.as_bytes())
+    }
+
+    #[test]
+    fn test_mb_value_parse() {
+        let priv_key = encode(ssi_multicodec::ED25519_PRIV, &[0x07; 32]);
+        let signature = encode(ssi_multicodec::EDDSA, &[0x07; 64]);
+        let other = encode(0x1234567, b"HIPPO");
+        for (s, expected_codec_category) in [
+            (
+                "uHiBKHZUE3HHlYcyVIF-vPm0Xg71vqJla2L1OGXHMSK4NEA",
+                CodecCategory::Multihash,
+            ),
+            (
+                "z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp",
+                CodecCategory::PubKey,
+            ),
+            (priv_key.as_str(), CodecCategory::PrivKey),
+            (signature.as_str(), CodecCategory::Signature),
+            (other.as_str(), CodecCategory::Unspecified),
+        ] {
+            let mb_value = MBValue::parse(s).expect("pass");
+            println!("mb_value: {:?}", mb_value);
+            assert_eq!(mb_value.codec_category(), expected_codec_category);
+            assert_eq!(mb_value.as_str(), s);
+
+            let mb_value_ref = MBValueRef::parse(s).expect("pass");
+            assert_eq!(mb_value_ref, mb_value.as_value_ref());
+            assert_eq!(mb_value_ref.to_value(), mb_value);
+        }
+
+        let mb_value = MBValue::parse(&priv_key).expect("pass");
+        assert!(matches!(mb_value, MBValue::PrivKey(_)));
+        assert!(!format!("{:?}", mb_value).contains(priv_key.as_str()));
+        let MBValue::PrivKey(mb_priv_key) = mb_value else {
+            panic!("expected MBValue::PrivKey");
+        };
+        assert_eq!(
+            mb_priv_key,
+            MBPrivKey::encoded(Base::Base58Btc, ssi_multicodec::ED25519_PRIV, &[0x07; 32])
+                .expect("pass")
+        );
+
+        // Validation of the detected type still applies.
+        let short_pub_key = encode(ssi_multicodec::ED25519_PUB, &[0x07; 31]);
+        for err in [
+            MBValue::parse(&short_pub_key).expect_err("pass"),
+            MBValueRef::parse(&short_pub_key).expect_err("pass"),
+        ] {
+            println!("err: {}", err);
+            assert_eq!(
+                err.kind(),
+                &ErrorKind::WrongByteLength {
+                    codec: ssi_multicodec::ED25519_PUB,
+                    expected: 32,
+                    actual: 31,
+                }
+            );
+        }
+        assert_eq!(
+            MBValue::parse("!abc").expect_err("pass").kind(),
+            &ErrorKind::InvalidBase
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_mb_value_serde() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct Document<'a> {
+            value_v: Vec<MBValue>,
+            #[serde(borrow)]
+            value_ref: MBValueRef<'a>,
+        }
+
+        let json = r#"{"value_v":["uHiBKHZUE3HHlYcyVIF-vPm0Xg71vqJla2L1OGXHMSK4NEA","z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp"],"value_ref":"z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp"}"#;
+        let document: Document = serde_json::from_str(json).expect("pass");
+        println!("document: {:?}", document);
+        assert!(matches!(document.value_v[0], MBValue::Hash(_)));
+        assert!(matches!(document.value_v[1], MBValue::PubKey(_)));
+        assert!(matches!(document.value_ref, MBValueRef::PubKey(_)));
+        assert_eq!(serde_json::to_string(&document).expect("pass"), json);
+
+        assert!(serde_json::from_str::<MBValue>(r#""!abc""#).is_err());
+    }
+}
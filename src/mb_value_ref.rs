@@ -0,0 +1,100 @@
+use crate::{
+    CodecCategory, MBAny, MBAnyStr, MBHash, MBHashStr, MBPrivKey, MBPrivKeyStr, MBPubKey,
+    MBPubKeyStr, MBValue, MBX, MBXStr, Result, SignatureCategory, mb_value::validate_mb_value,
+};
+
+/// The borrowed form of `MBValue`, i.e. a multibase str whose type is determined at runtime from its
+/// codec category.  See `MBValue`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MBValueRef<'a> {
+    Hash(&'a MBHashStr),
+    PubKey(&'a MBPubKeyStr),
+    PrivKey(&'a MBPrivKeyStr),
+    Signature(&'a MBXStr<SignatureCategory>),
+    Other(&'a MBAnyStr),
+}
+
+impl<'a> MBValueRef<'a> {
+    /// Parses the string, decoding it only once to determine its codec category and validate it as
+    /// the corresponding type.
+    pub fn parse(s: &'a str) -> Result<Self> {
+        Ok(match validate_mb_value(s)? {
+            CodecCategory::Multihash => Self::Hash(MBHashStr::from_validated_str(s)),
+            CodecCategory::PubKey => Self::PubKey(MBPubKeyStr::from_validated_str(s)),
+            CodecCategory::PrivKey => Self::PrivKey(MBPrivKeyStr::from_validated_str(s)),
+            CodecCategory::Signature => Self::Signature(MBXStr::from_validated_str(s)),
+            CodecCategory::SymmetricKey | CodecCategory::Unspecified => {
+                Self::Other(MBAnyStr::from_validated_str(s))
+            }
+        })
+    }
+    /// Returns the codec category of this value, which determines the variant.  Note that `Other` may
+    /// be any category other than the ones having their own variants.
+    pub fn codec_category(&self) -> CodecCategory {
+        match self {
+            Self::Hash(_) => CodecCategory::Multihash,
+            Self::PubKey(_) => CodecCategory::PubKey,
+            Self::PrivKey(_) => CodecCategory::PrivKey,
+            Self::Signature(_) => CodecCategory::Signature,
+            Self::Other(mb_any_str) => mb_any_str
+                .decoded()
+                .map_or(CodecCategory::Unspecified, |decoded| {
+                    CodecCategory::from_codec(decoded.codec())
+                }),
+        }
+    }
+    /// Returns the multibase string.
+    pub fn as_str(&self) -> &'a str {
+        match *self {
+            Self::Hash(mb_hash_str) => mb_hash_str.as_str(),
+            Self::PubKey(mb_pub_key_str) => mb_pub_key_str.as_str(),
+            Self::PrivKey(mb_priv_key_str) => mb_priv_key_str.as_str(),
+            Self::Signature(mb_signature_str) => mb_signature_str.as_str(),
+            Self::Other(mb_any_str) => mb_any_str.as_str(),
+        }
+    }
+    /// Returns the owned form of this value.
+    pub fn to_value(&self) -> MBValue {
+        let s = self.as_str().to_string();
+        match self {
+            Self::Hash(_) => MBValue::Hash(MBHash::from_validated_string(s)),
+            Self::PubKey(_) => MBValue::PubKey(MBPubKey::from_validated_string(s)),
+            Self::PrivKey(_) => MBValue::PrivKey(MBPrivKey::from_validated_string(s)),
+            Self::Signature(_) => MBValue::Signature(MBX::from_validated_string(s)),
+            Self::Other(_) => MBValue::Other(MBAny::from_validated_string(s)),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MBValueRef<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MBValueRef<'de> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct V;
+        impl<'de> serde::de::Visitor<'de> for V {
+            type Value = MBValueRef<'de>;
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("a borrowed MBValueRef")
+            }
+            fn visit_borrowed_str<E>(self, v: &'de str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                MBValueRef::parse(v).map_err(E::custom)
+            }
+        }
+        deserializer.deserialize_str(V)
+    }
+}
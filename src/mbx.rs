@@ -11,6 +11,10 @@ use crate::{CodecCategorizableT, Error, MBXStr};
 pub struct MBX<C: CodecCategorizableT>(std::marker::PhantomData<C>, String);
 
 impl<C: CodecCategorizableT> MBX<C> {
+    /// Wraps a string that the caller has already validated as an `MBXStr<C>`.
+    pub(crate) fn from_validated_string(s: String) -> Self {
+        Self(std::marker::PhantomData, s)
+    }
     // Note that this doesn't actually check the bytes against the codec (at the very least it should check the
     // length of the bytes against the codec).  TODO: Do this check.
     pub fn encoded(base: multibase::Base, codec: u64, byte_v: &[u8]) -> Result<Self, Error> {
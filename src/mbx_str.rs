@@ -1,4 +1,7 @@
-use crate::{CodecCategorizableT, CodecCategory, ED448_PRIV_CODEC, Error, ensure};
+use crate::{
+    CodecCategorizableT, CodecCategory, CodecName, Diagnosis, Error, ErrorKind,
+    diagnosis::diagnose_impl, ensure,
+};
 
 /// This newtype is a str that is defined to be `multibase(base, varint(codec) || bytes)`, where the
 /// codec is restricted to a specific CodecCategory.  The `X` in `MBXStr` signifies a placeholder.
@@ -11,6 +14,12 @@ use crate::{CodecCategorizableT, CodecCategory, ED448_PRIV_CODEC, Error, ensure}
 pub struct MBXStr<C: CodecCategorizableT>(std::marker::PhantomData<C>, str);
 
 impl<C: CodecCategorizableT> MBXStr<C> {
+    /// Wraps a str that the caller has already validated as an `MBXStr<C>`.
+    pub(crate) fn from_validated_str(s: &str) -> &Self {
+        // SAFETY: MBXStr<C> is `#[repr(transparent)]` over `str` (the PhantomData is zero-sized), and
+        // the caller has validated `s`.
+        unsafe { &*(s as *const str as *const Self) }
+    }
     /// Returns the base of this MBXStr.
     pub fn base(&self) -> multibase::Base {
         multibase::Base::from_code(self.base_char()).expect("programmer error")
@@ -24,6 +33,11 @@ impl<C: CodecCategorizableT> MBXStr<C> {
         let (_base, multicodec_byte_v) = multibase::decode(&self.1)?;
         Ok(ssi_multicodec::MultiEncodedBuf::new(multicodec_byte_v)?)
     }
+    /// Parses the given string as an `MBXStr<C>` step by step and reports what was found, including
+    /// where it went wrong if it's malformed.  Unlike `new_ref`, this never fails.  See `Diagnosis`.
+    pub fn diagnose(data: &str) -> Diagnosis {
+        diagnose_impl::<C>(data)
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -53,91 +67,31 @@ impl<C: CodecCategorizableT> pneutype::Validate for MBXStr<C> {
     type Data = str;
     type Error = Error;
     fn validate(data: &Self::Data) -> Result<(), Self::Error> {
-        mbx_str_validate_impl(data, C::codec_category())
+        mbx_str_validate_impl::<C>(data)
     }
 }
 
-/// Validates a multibase string against an expected codec category.  This is used in multiple places in this crate.
-pub(crate) fn mbx_str_validate_impl(
-    data: &str,
-    expected_codec_category: CodecCategory,
-) -> Result<(), Error> {
+/// Validates a multibase string against the codec category `C`, using its `accepts_codec` and
+/// `validate_codec_data`.  This is used in multiple places in this crate.
+pub(crate) fn mbx_str_validate_impl<C: CodecCategorizableT>(data: &str) -> Result<(), Error> {
     // TODO: Ideally we could simply validate the multibase string without allocating.
     let (_decoded_base, decoded_byte_v) = multibase::decode(data)?;
     let multi_encoded = ssi_multicodec::MultiEncodedBuf::new(decoded_byte_v)?;
-    let codec_category = CodecCategory::from_codec(multi_encoded.codec());
-    // TODO: Could use cfg_select macro, but that requires Rust 1.95 or later.
-    #[cfg(feature = "codec-str")]
-    ensure!(
-        codec_category == expected_codec_category,
-        "expected codec {:?} (0x{:02x}) to be in category {:?} but it was in category {:?}",
-        crate::codec_str(multi_encoded.codec()),
-        multi_encoded.codec(),
-        expected_codec_category,
-        codec_category,
-    );
-    #[cfg(not(feature = "codec-str"))]
+    let codec = multi_encoded.codec();
+    let codec_category = CodecCategory::from_codec(codec);
     ensure!(
-        codec_category == expected_codec_category,
-        "expected codec 0x{:02x} to be in category {:?} but it was in category {:?}",
-        multi_encoded.codec(),
-        expected_codec_category,
+        C::accepts_codec(codec),
+        kind: ErrorKind::WrongCodecCategory {
+            expected: C::codec_category(),
+            actual: codec_category,
+        },
+        "expected codec {} to be in category {:?} but it was in category {:?}",
+        CodecName(codec),
+        C::codec_category(),
         codec_category,
     );
-
-    // Codec-specific validation.  For now, just validate the expected byte length.
-
-    // Validate the expected byte length.
-    // References:
-    // - <https://w3c-ccg.github.io/did-key-spec/#signature-method-creation-algorithm>
-    let expected_byte_len_o = match multi_encoded.codec() {
-        // Private key types
-        ssi_multicodec::ED25519_PRIV => Some(32),
-        // NOTE: The codec ED448_PRIV is not yet supported by the ssi_multicodec crate,
-        // hence the hardcoded value.  See https://github.com/multiformats/multicodec/pull/390
-        // TODO: Eventually replace ED448_PRIV_CODEC with `ssi_multicodec::ED448_PRIV => { ... }`
-        ED448_PRIV_CODEC => Some(57),
-        ssi_multicodec::P256_PRIV => Some(32),
-        ssi_multicodec::P384_PRIV => Some(48),
-        // Reference: https://software-dl.ti.com/simplelink/esd/simplelink_lowpower_f3_sdk/8.10.00.55/exports/docs/drivers/doxygen/html/group__nistp521__params.html#ga44f08f1390d90127f0decb3c08c82664
-        ssi_multicodec::P521_PRIV => Some(66),
-        ssi_multicodec::SECP256K1_PRIV => Some(32),
-        // Public key types
-        ssi_multicodec::ED25519_PUB => Some(32),
-        ssi_multicodec::ED448_PUB => Some(57),
-        // Compressed format.
-        ssi_multicodec::P256_PUB => Some(33),
-        // Compressed format.
-        ssi_multicodec::P384_PUB => Some(49),
-        // Compressed format.
-        ssi_multicodec::P521_PUB => Some(67),
-        // Compressed format.
-        ssi_multicodec::SECP256K1_PUB => Some(33),
-        ssi_multicodec::X25519_PUB => Some(32),
-        // No checking for other types for now.
-        _ => None,
-    };
-    if let Some(expected_byte_len) = expected_byte_len_o {
-        #[cfg(feature = "codec-str")]
-        ensure!(
-            multi_encoded.data().len() == expected_byte_len,
-            "codec {:?} (0x{:02x}) expected {} bytes but got {}",
-            crate::codec_str(multi_encoded.codec()),
-            multi_encoded.codec(),
-            expected_byte_len,
-            multi_encoded.data().len()
-        );
-        #[cfg(not(feature = "codec-str"))]
-        ensure!(
-            multi_encoded.data().len() == expected_byte_len,
-            "codec 0x{:02x} expected {} bytes but got {}",
-            multi_encoded.codec(),
-            expected_byte_len,
-            multi_encoded.data().len()
-        );
-    }
-
-    Ok(())
+    // Codec-specific validation, e.g. the expected byte length.
+    C::validate_codec_data(codec, multi_encoded.data())
 }
 
 #[cfg(test)]
@@ -239,4 +193,99 @@ mod tests {
             // assert_eq!(decoded_multi_encoded.data(), byte_v);
         }
     }
+
+    #[test]
+    fn test_mbx_str_error_kind() {
+        let encode = |codec: u64, byte_v: &[u8]| {
+            let multi_encoded = ssi_multicodec::MultiEncodedBuf::encode_bytes(codec, byte_v);
+            multibase::encode(multibase::Base::Base58Btc, multi_encoded.as_bytes())
+        };
+
+        let err = MBXStr::<PubKeyCategory>::new_ref("!abc").expect_err("pass");
+        println!("err: {}", err);
+        assert_eq!(err.kind(), &ErrorKind::InvalidBase);
+
+        // A varint whose continuation bit is set but which has no following byte.
+        let truncated_varint = multibase::encode(multibase::Base::Base58Btc, [0x80u8]);
+        let err = MBXStr::<PubKeyCategory>::new_ref(&truncated_varint).expect_err("pass");
+        println!("err: {}", err);
+        assert_eq!(err.kind(), &ErrorKind::InvalidVarint);
+
+        let priv_key = encode(ssi_multicodec::ED25519_PRIV, &[0u8; 32]);
+        let err = MBXStr::<PubKeyCategory>::new_ref(&priv_key).expect_err("pass");
+        println!("err: {}", err);
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::WrongCodecCategory {
+                expected: CodecCategory::PubKey,
+                actual: CodecCategory::PrivKey,
+            }
+        );
+
+        let short_pub_key = encode(ssi_multicodec::ED25519_PUB, &[0u8; 31]);
+        let err = MBXStr::<PubKeyCategory>::new_ref(&short_pub_key).expect_err("pass");
+        println!("err: {}", err);
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::WrongByteLength {
+                codec: ssi_multicodec::ED25519_PUB,
+                expected: 32,
+                actual: 31,
+            }
+        );
+        // The message is unchanged by the addition of the error kind.
+        assert!(err.to_string().ends_with("expected 32 bytes but got 31"));
+    }
+
+    /// A custom category which only accepts ed25519 public keys, and which validates the key bytes
+    /// itself instead of using the default length check.
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    struct Ed25519PubCategory;
+
+    impl CodecCategorizableT for Ed25519PubCategory {
+        fn codec_category() -> CodecCategory {
+            CodecCategory::PubKey
+        }
+        fn accepts_codec(codec: u64) -> bool {
+            codec == ssi_multicodec::ED25519_PUB
+        }
+        fn validate_codec_data(_codec: u64, data: &[u8]) -> crate::Result<()> {
+            crate::ensure!(data.len() == 32, "expected 32 bytes but got {}", data.len());
+            crate::ensure!(data.iter().any(|byte| *byte != 0), "all-zero key");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_mbx_str_custom_category() {
+        let encode = |codec: u64, byte_v: &[u8]| {
+            let multi_encoded = ssi_multicodec::MultiEncodedBuf::encode_bytes(codec, byte_v);
+            multibase::encode(multibase::Base::Base58Btc, multi_encoded.as_bytes())
+        };
+
+        assert!(
+            MBXStr::<Ed25519PubCategory>::new_ref(
+                "z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp"
+            )
+            .is_ok()
+        );
+
+        let secp256k1_pub_key = "zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme";
+        assert!(MBXStr::<PubKeyCategory>::new_ref(secp256k1_pub_key).is_ok());
+        let err = MBXStr::<Ed25519PubCategory>::new_ref(secp256k1_pub_key).expect_err("pass");
+        println!("err: {}", err);
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::WrongCodecCategory {
+                expected: CodecCategory::PubKey,
+                actual: CodecCategory::PubKey,
+            }
+        );
+
+        let zero_pub_key = encode(ssi_multicodec::ED25519_PUB, &[0u8; 32]);
+        assert!(MBXStr::<PubKeyCategory>::new_ref(&zero_pub_key).is_ok());
+        let err = MBXStr::<Ed25519PubCategory>::new_ref(&zero_pub_key).expect_err("pass");
+        println!("err: {}", err);
+        assert!(!MBXStr::<Ed25519PubCategory>::diagnose(&zero_pub_key).is_valid());
+    }
 }
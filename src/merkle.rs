@@ -0,0 +1,353 @@
+use crate::{Base, ErrorKind, Hasher, MBHash, MBHashStr, Result, bail, ensure};
+
+/// Domain separation prefix byte for hashing a leaf (see <https://www.rfc-editor.org/rfc/rfc6962#section-2.1>).
+const LEAF_PREFIX: u8 = 0x00;
+/// Domain separation prefix byte for hashing an interior node (see <https://www.rfc-editor.org/rfc/rfc6962#section-2.1>).
+const NODE_PREFIX: u8 = 0x01;
+
+/// A Merkle tree over a sequence of `MBHashStr` leaves, all having the same codec.  The tree uses the
+/// hash function given by that codec, so e.g. a tree over `sha2-256` leaves has a `sha2-256` root.
+///
+/// Each leaf is hashed as `H(0x00 || leaf_digest)` and each interior node as `H(0x01 || left || right)`,
+/// so that a leaf hash can't be passed off as an interior node hash.  If a level has an odd number of
+/// nodes, the last node is promoted to the next level unchanged (it is not duplicated).
+#[derive(Clone, Debug)]
+pub struct MerkleTree {
+    base: Base,
+    codec: u64,
+    /// Level 0 holds the leaf hashes and the last level holds only the root.
+    level_v: Vec<Vec<Vec<u8>>>,
+}
+
+impl MerkleTree {
+    /// Computes the Merkle tree over the given leaves.  The root and the siblings in inclusion proofs
+    /// are encoded in the given base.  Returns an error if there are no leaves, if the leaves don't all
+    /// have the same codec, or if that codec is not a supported hash function (see `Hasher`).
+    pub fn new<'a>(base: Base, leaves: impl IntoIterator<Item = &'a MBHashStr>) -> Result<Self> {
+        let mut codec_o = None;
+        let mut leaf_hash_v = Vec::new();
+        for (leaf_index, leaf) in leaves.into_iter().enumerate() {
+            let multihash = leaf.decoded::<64>()?;
+            let codec = *codec_o.get_or_insert(multihash.code());
+            ensure!(
+                multihash.code() == codec,
+                kind: ErrorKind::WrongCodec {
+                    expected: codec,
+                    actual: multihash.code(),
+                },
+                "Merkle tree leaves must all have the same codec; leaf 0 has codec 0x{:02x} but leaf {} has codec 0x{:02x}",
+                codec,
+                leaf_index,
+                multihash.code()
+            );
+            leaf_hash_v.push(hash_leaf(codec, multihash.digest())?);
+        }
+        let Some(codec) = codec_o else {
+            bail!("Merkle tree must have at least one leaf");
+        };
+
+        let mut level_v = vec![leaf_hash_v];
+        while level_v.last().unwrap().len() > 1 {
+            let level = level_v.last().unwrap();
+            let mut next_level = Vec::with_capacity(level.len().div_ceil(2));
+            for pair in level.chunks(2) {
+                match pair {
+                    [left, right] => next_level.push(hash_node(codec, left, right)?),
+                    [promoted] => next_level.push(promoted.clone()),
+                    _ => unreachable!(),
+                }
+            }
+            level_v.push(next_level);
+        }
+
+        Ok(Self {
+            base,
+            codec,
+            level_v,
+        })
+    }
+    /// Returns the codec of the leaves, which is also the codec of the root.
+    pub fn codec(&self) -> u64 {
+        self.codec
+    }
+    /// Returns the number of leaves in the tree.
+    pub fn leaf_count(&self) -> usize {
+        self.level_v[0].len()
+    }
+    /// Returns the root of the tree.
+    pub fn root(&self) -> MBHash {
+        let root = &self.level_v.last().unwrap()[0];
+        MBHash::encoded::<64>(self.base, self.codec, root).unwrap()
+    }
+    /// Produces a proof that the leaf at the given index is included in this tree.
+    pub fn inclusion_proof(&self, leaf_index: usize) -> Result<MerkleProof> {
+        ensure!(
+            leaf_index < self.leaf_count(),
+            "leaf index {} is out of range for Merkle tree with {} leaves",
+            leaf_index,
+            self.leaf_count()
+        );
+        let mut sibling_v = Vec::new();
+        let mut index = leaf_index;
+        for level in &self.level_v[..self.level_v.len() - 1] {
+            let sibling_index = index ^ 1;
+            if let Some(sibling) = level.get(sibling_index) {
+                sibling_v.push(MBHash::encoded::<64>(self.base, self.codec, sibling)?);
+            }
+            index /= 2;
+        }
+        Ok(MerkleProof {
+            leaf_index,
+            leaf_count: self.leaf_count(),
+            sibling_v,
+        })
+    }
+}
+
+/// A proof that a leaf is included in a Merkle tree with a given root (see `MerkleTree`).  The siblings
+/// are ordered from the leaf level up to just below the root.  The position of each sibling (left or
+/// right) is determined by `leaf_index` and `leaf_count`, so it is not stored.
+///
+/// Note that the root doesn't commit to the leaf count, so `leaf_index` and `leaf_count` are not
+/// authenticated by the proof itself (e.g. the last leaf of a 3-leaf tree is promoted, so it also
+/// verifies as leaf 1 of a 2-leaf tree).  Hence `verify` takes the expected values from the caller.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MerkleProof {
+    pub leaf_index: usize,
+    pub leaf_count: usize,
+    pub sibling_v: Vec<MBHash>,
+}
+
+impl MerkleProof {
+    /// Verifies that the given leaf is included at `leaf_index` in the Merkle tree having `leaf_count`
+    /// leaves and the given root.  The leaf index and leaf count must come from the caller (e.g. from
+    /// the position of the entry in a trusted manifest), since they are not authenticated by the root;
+    /// the proof fails to verify if its own `leaf_index` or `leaf_count` differ from them.
+    pub fn verify(
+        &self,
+        leaf: &MBHashStr,
+        leaf_index: usize,
+        leaf_count: usize,
+        root: &MBHashStr,
+    ) -> Result<()> {
+        ensure!(
+            leaf_index < leaf_count,
+            "leaf index {} is out of range for Merkle tree with {} leaves",
+            leaf_index,
+            leaf_count
+        );
+        ensure!(
+            self.leaf_index == leaf_index && self.leaf_count == leaf_count,
+            kind: ErrorKind::VerificationFailed,
+            "Merkle proof is for leaf {} of {} but leaf {} of {} was expected",
+            self.leaf_index,
+            self.leaf_count,
+            leaf_index,
+            leaf_count
+        );
+        let root_multihash = root.decoded::<64>()?;
+        let codec = root_multihash.code();
+        let leaf_multihash = leaf.decoded::<64>()?;
+        ensure!(
+            leaf_multihash.code() == codec,
+            kind: ErrorKind::WrongCodec {
+                expected: codec,
+                actual: leaf_multihash.code(),
+            },
+            "leaf codec 0x{:02x} does not match root codec 0x{:02x}",
+            leaf_multihash.code(),
+            codec
+        );
+
+        let mut hash = hash_leaf(codec, leaf_multihash.digest())?;
+        let mut sibling_it = self.sibling_v.iter();
+        let mut index = self.leaf_index;
+        let mut level_len = self.leaf_count;
+        while level_len > 1 {
+            let sibling_index = index ^ 1;
+            if sibling_index < level_len {
+                let Some(sibling) = sibling_it.next() else {
+                    bail!(kind: ErrorKind::VerificationFailed, "Merkle proof has too few siblings");
+                };
+                let sibling_multihash = sibling.decoded::<64>()?;
+                ensure!(
+                    sibling_multihash.code() == codec,
+                    kind: ErrorKind::WrongCodec {
+                        expected: codec,
+                        actual: sibling_multihash.code(),
+                    },
+                    "sibling codec 0x{:02x} does not match root codec 0x{:02x}",
+                    sibling_multihash.code(),
+                    codec
+                );
+                hash = if sibling_index > index {
+                    hash_node(codec, &hash, sibling_multihash.digest())?
+                } else {
+                    hash_node(codec, sibling_multihash.digest(), &hash)?
+                };
+            }
+            index /= 2;
+            level_len = level_len.div_ceil(2);
+        }
+        ensure!(
+            sibling_it.next().is_none(),
+            kind: ErrorKind::VerificationFailed,
+            "Merkle proof has too many siblings"
+        );
+        ensure!(
+            hash.as_slice() == root_multihash.digest(),
+            kind: ErrorKind::VerificationFailed,
+            "Merkle proof does not match the root"
+        );
+        Ok(())
+    }
+}
+
+fn hash_leaf(codec: u64, leaf_digest: &[u8]) -> Result<Vec<u8>> {
+    let mut hasher = Hasher::new(codec)?;
+    hasher.update(&[LEAF_PREFIX]);
+    hasher.update(leaf_digest);
+    Ok(hasher.finalize_digest())
+}
+
+fn hash_node(codec: u64, left: &[u8], right: &[u8]) -> Result<Vec<u8>> {
+    let mut hasher = Hasher::new(codec)?;
+    hasher.update(&[NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    Ok(hasher.finalize_digest())
+}
+
+#[cfg(all(test, feature = "blake3"))]
+mod tests {
+    use crate::{Base, ErrorKind, MBHash, MerkleTree};
+
+    fn make_leaf_v(leaf_count: usize) -> Vec<MBHash> {
+        (0..leaf_count)
+            .map(|i| {
+                let mut hasher = blake3::Hasher::new();
+                hasher.update(format!("HIPPO {}", i).as_bytes());
+                MBHash::from_blake3(Base::Base64Url, hasher)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merkle_tree_inclusion_proofs() {
+        for leaf_count in 1..=9 {
+            let leaf_v = make_leaf_v(leaf_count);
+            let merkle_tree = MerkleTree::new(
+                Base::Base58Btc,
+                leaf_v.iter().map(|leaf| leaf.as_mb_hash_str()),
+            )
+            .expect("pass");
+            assert_eq!(merkle_tree.leaf_count(), leaf_count);
+            let root = merkle_tree.root();
+            println!("leaf_count: {}, root: {:?}", leaf_count, root);
+            assert_eq!(
+                root.decoded::<64>().expect("pass").code(),
+                ssi_multicodec::BLAKE3
+            );
+
+            for (leaf_index, leaf) in leaf_v.iter().enumerate() {
+                let proof = merkle_tree.inclusion_proof(leaf_index).expect("pass");
+                proof
+                    .verify(leaf, leaf_index, leaf_count, &root)
+                    .expect("pass");
+
+                // A different leaf must not verify at this index.
+                let other_leaf = &leaf_v[(leaf_index + 1) % leaf_count];
+                if leaf_count > 1 {
+                    assert!(
+                        proof
+                            .verify(other_leaf, leaf_index, leaf_count, &root)
+                            .is_err()
+                    );
+                }
+
+                // A tampered leaf index must not verify.
+                let mut bad_proof = proof.clone();
+                bad_proof.leaf_index = leaf_count;
+                assert!(
+                    bad_proof
+                        .verify(leaf, leaf_index, leaf_count, &root)
+                        .is_err()
+                );
+                assert!(
+                    bad_proof
+                        .verify(leaf, leaf_count, leaf_count + 1, &root)
+                        .is_err()
+                );
+            }
+            assert!(merkle_tree.inclusion_proof(leaf_count).is_err());
+        }
+    }
+
+    #[test]
+    fn test_merkle_proof_position_is_not_forgeable() {
+        // In a 3-leaf tree, leaf 2 is promoted to the next level unchanged, so the proof for it is
+        // the same as for leaf 1 of a 2-leaf tree having the same root.  A proof claiming that
+        // position must not verify against the position that the caller expects.
+        let leaf_v = make_leaf_v(3);
+        let merkle_tree = MerkleTree::new(
+            Base::Base64Url,
+            leaf_v.iter().map(|leaf| leaf.as_mb_hash_str()),
+        )
+        .expect("pass");
+        let root = merkle_tree.root();
+        let proof = merkle_tree.inclusion_proof(2).expect("pass");
+        proof.verify(&leaf_v[2], 2, 3, &root).expect("pass");
+
+        let mut forged_proof = proof.clone();
+        forged_proof.leaf_index = 1;
+        forged_proof.leaf_count = 2;
+        let err = forged_proof
+            .verify(&leaf_v[2], 2, 3, &root)
+            .expect_err("pass");
+        println!("err: {}", err);
+        assert_eq!(err.kind(), &ErrorKind::VerificationFailed);
+
+        // Changing only the leaf index or only the leaf count is rejected too.
+        for (forged_leaf_index, forged_leaf_count) in [(1, 3), (2, 4)] {
+            let mut forged_proof = proof.clone();
+            forged_proof.leaf_index = forged_leaf_index;
+            forged_proof.leaf_count = forged_leaf_count;
+            assert!(forged_proof.verify(&leaf_v[2], 2, 3, &root).is_err());
+        }
+
+        // The honest proof doesn't verify at a different claimed position.
+        assert!(proof.verify(&leaf_v[2], 1, 2, &root).is_err());
+        assert!(proof.verify(&leaf_v[2], 1, 3, &root).is_err());
+    }
+
+    #[test]
+    fn test_merkle_tree_domain_separation() {
+        // A single-leaf tree's root is the leaf hash, which must differ from the leaf itself.
+        let leaf_v = make_leaf_v(1);
+        let merkle_tree = MerkleTree::new(
+            Base::Base64Url,
+            leaf_v.iter().map(|leaf| leaf.as_mb_hash_str()),
+        )
+        .expect("pass");
+        assert_ne!(merkle_tree.root(), leaf_v[0]);
+    }
+
+    #[test]
+    fn test_merkle_tree_errors() {
+        assert!(MerkleTree::new(Base::Base64Url, std::iter::empty()).is_err());
+
+        let mut leaf_v = make_leaf_v(2);
+        leaf_v.push(
+            MBHash::encoded::<64>(Base::Base64Url, ssi_multicodec::SHA2_256, &[0u8; 32])
+                .expect("pass"),
+        );
+        assert!(
+            MerkleTree::new(
+                Base::Base64Url,
+                leaf_v.iter().map(|leaf| leaf.as_mb_hash_str())
+            )
+            .is_err()
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{Error, MBPrivKey, MBPrivKeyStr, MBPubKey, MBPubKeyStr, ensure};
+use crate::{
+    Error, ErrorKind, MBPrivKey, MBPrivKeyOf, MBPrivKeyStr, MBPubKey, MBPubKeyOf, MBPubKeyStr,
+    P384, ensure,
+};
 
 //
 // SigningKey
@@ -10,6 +13,10 @@ impl TryFrom<&MBPrivKeyStr> for p384::ecdsa::SigningKey {
         let decoded = mb_priv_key.decoded()?;
         ensure!(
             decoded.codec() == ssi_multicodec::P384_PRIV,
+            kind: ErrorKind::WrongCodec {
+                expected: ssi_multicodec::P384_PRIV,
+                actual: decoded.codec(),
+            },
             "Expected codec P384_PRIV 0x({:02x}), got 0x{:02x}",
             ssi_multicodec::P384_PRIV,
             decoded.codec()
@@ -44,6 +51,10 @@ impl TryFrom<&MBPubKeyStr> for p384::ecdsa::VerifyingKey {
         let decoded = mb_pub_key.decoded()?;
         ensure!(
             decoded.codec() == ssi_multicodec::P384_PUB,
+            kind: ErrorKind::WrongCodec {
+                expected: ssi_multicodec::P384_PUB,
+                actual: decoded.codec(),
+            },
             "Expected codec P384_PUB (0x{:02x}), got 0x{:02x}",
             ssi_multicodec::P384_PUB,
             decoded.codec()
@@ -69,6 +80,36 @@ impl TryFrom<MBPubKey> for p384::ecdsa::VerifyingKey {
     }
 }
 
+//
+// MBPrivKeyOf and MBPubKeyOf
+//
+
+// These are infallible by the validation invariant of `KeyCodecT`.
+
+impl From<&MBPrivKeyOf<P384>> for p384::ecdsa::SigningKey {
+    fn from(mb_priv_key: &MBPrivKeyOf<P384>) -> Self {
+        Self::try_from(mb_priv_key.as_mb_priv_key_str()).expect("programmer error")
+    }
+}
+
+impl From<MBPrivKeyOf<P384>> for p384::ecdsa::SigningKey {
+    fn from(mb_priv_key: MBPrivKeyOf<P384>) -> Self {
+        Self::from(&mb_priv_key)
+    }
+}
+
+impl From<&MBPubKeyOf<P384>> for p384::ecdsa::VerifyingKey {
+    fn from(mb_pub_key: &MBPubKeyOf<P384>) -> Self {
+        Self::try_from(mb_pub_key.as_mb_pub_key_str()).expect("programmer error")
+    }
+}
+
+impl From<MBPubKeyOf<P384>> for p384::ecdsa::VerifyingKey {
+    fn from(mb_pub_key: MBPubKeyOf<P384>) -> Self {
+        Self::from(&mb_pub_key)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Base, MBPrivKey, MBPubKey};
@@ -1,4 +1,4 @@
-use crate::{Error, MBPrivKey, MBPrivKeyStr, MBPubKey, MBPubKeyStr};
+use crate::{Error, ErrorKind, MBPrivKey, MBPrivKeyStr, MBPubKey, MBPubKeyStr};
 
 //
 // SignerBytes
@@ -22,7 +22,9 @@ impl TryFrom<&MBPrivKeyStr> for signature_dyn::SignerBytes {
     type Error = Error;
     fn try_from(mb_priv_key_str: &MBPrivKeyStr) -> std::result::Result<Self, Self::Error> {
         let decoded = mb_priv_key_str.decoded().expect("programmer error");
-        let key_type = signature_dyn::KeyType::try_from_priv_key_codec(decoded.codec())?;
+        let codec = decoded.codec();
+        let key_type = signature_dyn::KeyType::try_from_priv_key_codec(codec)
+            .map_err(|e| Error::from(e).with_kind(ErrorKind::UnsupportedCodec { codec }))?;
         Ok(signature_dyn::SignerBytes::new(
             key_type,
             decoded.data().to_owned().into(),
@@ -52,7 +54,9 @@ impl<'a> TryFrom<&MBPubKeyStr> for signature_dyn::VerifierBytes<'a> {
     type Error = Error;
     fn try_from(mb_pub_key_str: &MBPubKeyStr) -> std::result::Result<Self, Self::Error> {
         let decoded = mb_pub_key_str.decoded().expect("programmer error");
-        let key_type = signature_dyn::KeyType::try_from_pub_key_codec(decoded.codec())?;
+        let codec = decoded.codec();
+        let key_type = signature_dyn::KeyType::try_from_pub_key_codec(codec)
+            .map_err(|e| Error::from(e).with_kind(ErrorKind::UnsupportedCodec { codec }))?;
         Ok(signature_dyn::VerifierBytes::new(
             key_type,
             decoded.data().to_owned().into(),
@@ -5,23 +5,12 @@ fn test_mb_hash_placeholder_case(base: multibase::Base, codec: u64, digest_byte_
 
     // NOTE: We use 64 here to accomodate the largest expected digest size (SHA2-512 is 64 bytes).
     let mb_hash = MBHash::encoded::<64>(base, codec, digest_byte_v).expect("pass");
-    #[cfg(feature = "codec-str")]
-    {
-        println!(
-            "placeholder case: base: {:?}, codec: {} (0x{:02x}), mb_hash: {:?}",
-            base,
-            mbx::codec_str(codec).expect("pass"),
-            codec,
-            mb_hash
-        );
-    }
-    #[cfg(not(feature = "codec-str"))]
-    {
-        println!(
-            "placeholder case: base: {:?}, codec: 0x{:02x}, mb_hash: {:?}",
-            base, codec, mb_hash
-        );
-    }
+    println!(
+        "placeholder case: base: {:?}, codec: {}, mb_hash: {:?}",
+        base,
+        mbx::CodecName(codec),
+        mb_hash
+    );
 }
 
 #[test]
@@ -315,20 +304,10 @@ fn test_mb_pub_key_str_decode() {
     for (codec, multibase_str) in test_case_v {
         let mb_pub_key = MBPubKeyStr::new_ref(multibase_str).expect("pass");
         let decoded = mb_pub_key.decoded().expect("pass");
-        #[cfg(feature = "codec-str")]
-        {
-            println!(
-                "mb_pub_key: {}, decoded.codec(): {:?} (0x{:02x})",
-                mb_pub_key,
-                mbx::codec_str(decoded.codec()).expect("pass"),
-                decoded.codec()
-            );
-        }
-        #[cfg(not(feature = "codec-str"))]
         println!(
-            "mb_pub_key: {}, decoded.codec(): 0x{:02x}",
+            "mb_pub_key: {}, decoded.codec(): {}",
             mb_pub_key,
-            decoded.codec()
+            mbx::CodecName(decoded.codec())
         );
         assert_eq!(decoded.codec(), codec, "multibase_str: {:?}", multibase_str);
     }